@@ -695,5 +695,187 @@ fn ui_box_tree(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, ui_box_tree);
+const LARGE_CELL_SIZE: f64 = 10.0;
+const LARGE_CELL_PITCH: f64 = 12.0;
+
+/// Flat grid of `n` small cells under a single root, approximating a large canvas document.
+fn build_large_grid_tree<B: Backend<f64>>(backend: B, n: usize) -> Tree<B> {
+    let mut tree = Tree::with_backend(backend);
+    let cols = (n as f64).sqrt().ceil() as usize;
+    let side = cols as f64 * LARGE_CELL_PITCH;
+    let root = tree.insert(
+        None,
+        LocalNode {
+            local_bounds: Rect::new(0.0, 0.0, side, side),
+            ..LocalNode::default()
+        },
+    );
+    for i in 0..n {
+        let x = (i % cols) as f64 * LARGE_CELL_PITCH;
+        let y = (i / cols) as f64 * LARGE_CELL_PITCH;
+        tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, LARGE_CELL_SIZE, LARGE_CELL_SIZE),
+                local_transform: Affine::translate(Vec2::new(x, y)),
+                z_index: (i % 7) as i32,
+                ..LocalNode::default()
+            },
+        );
+    }
+    let _ = tree.commit();
+    tree
+}
+
+fn bench_large_queries<B: Backend<f64>>(
+    g: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    n: usize,
+    backend: B,
+) {
+    let tree = build_large_grid_tree(backend, n);
+    let side = (n as f64).sqrt().ceil() * LARGE_CELL_PITCH;
+    // A fixed spread of probe points across the whole document.
+    let pts: Vec<Point> = (0..64)
+        .map(|i| {
+            let t = i as f64 / 64.0;
+            Point::new(side * t, side * (1.0 - t) * 0.999)
+        })
+        .collect();
+    let filter = QueryFilter::new().pickable();
+
+    g.bench_with_input(
+        BenchmarkId::new(format!("hit_test_point_{n}"), name),
+        &tree,
+        |b, tree| {
+            b.iter(|| {
+                for &p in &pts {
+                    black_box(tree.hit_test_point(black_box(p), filter));
+                }
+            });
+        },
+    );
+
+    // A viewport-sized region, as used to collect visible nodes for painting.
+    let viewport = Rect::new(0.0, 0.0, 1200.0, 800.0) + Vec2::new(side * 0.5, side * 0.5);
+    g.bench_with_input(
+        BenchmarkId::new(format!("intersect_rect_{n}"), name),
+        &tree,
+        |b, tree| {
+            b.iter(|| black_box(tree.intersect_rect(black_box(viewport), filter).count()));
+        },
+    );
+}
+
+/// Query cost on trees with 100k+ nodes, where only the hierarchical backends stay fast.
+///
+/// The flat-vector backend is left out on purpose: it scans every node per query.
+fn large_box_tree(c: &mut Criterion) {
+    let mut g = c.benchmark_group("large_box_tree");
+    g.warm_up_time(Duration::from_secs(1));
+    g.measurement_time(Duration::from_secs(3));
+    g.sample_size(20);
+
+    for n in [100_000, 250_000] {
+        bench_large_queries(
+            &mut g,
+            "grid_f64_100",
+            n,
+            understory_index::backends::GridF64::new(100.0),
+        );
+        bench_large_queries(
+            &mut g,
+            "rtree_f64",
+            n,
+            understory_index::backends::RTreeF64::<()>::default(),
+        );
+        bench_large_queries(
+            &mut g,
+            "bvh_f64",
+            n,
+            understory_index::backends::BvhF64::default(),
+        );
+    }
+
+    g.finish();
+}
+
+/// A chain of `depth` nested nodes, each clipped to a rounded rect slightly inside its parent.
+///
+/// Hit tests here are dominated by the ancestor clip walk rather than the spatial index.
+fn build_deep_clip_chain(depth: usize) -> Tree {
+    let mut tree = Tree::new();
+    let mut parent = None;
+    for _ in 0..depth {
+        let id = tree.insert(
+            parent,
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 1000.0, 1000.0),
+                local_transform: Affine::translate(Vec2::new(1.0, 1.0)),
+                local_clip: Some(RoundedRect::new(0.0, 0.0, 1000.0, 1000.0, 8.0)),
+                ..LocalNode::default()
+            },
+        );
+        parent = Some(id);
+    }
+    let _ = tree.commit();
+    tree
+}
+
+/// Deterministic pseudo-random points in `[0, side)^2` for hit-test storms.
+fn storm_points(count: usize, side: f64) -> Vec<Point> {
+    let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    (0..count)
+        .map(|_| Point::new(next() * side, next() * side))
+        .collect()
+}
+
+/// Deep clip nesting and hit-test storms: many queries per frame, as during a fast pointer drag
+/// with hover tracking over nested scroll containers.
+fn deep_box_tree(c: &mut Criterion) {
+    let mut g = c.benchmark_group("deep_box_tree");
+    g.warm_up_time(Duration::from_secs(1));
+    g.measurement_time(Duration::from_secs(3));
+
+    let filter = QueryFilter::new().pickable();
+    for depth in [16, 64, 256] {
+        let tree = build_deep_clip_chain(depth);
+        let pts = storm_points(1_000, 1_100.0);
+        g.bench_with_input(
+            BenchmarkId::new("hit_test_storm_1k", depth),
+            &tree,
+            |b, tree| {
+                b.iter(|| {
+                    for &p in &pts {
+                        black_box(tree.hit_test_point(black_box(p), filter));
+                    }
+                });
+            },
+        );
+    }
+
+    let (tree, _ids, _stats) = build_ui_box_tree(understory_index::backends::BvhF64::default());
+    let pts = storm_points(10_000, 1_200.0);
+    g.bench_with_input(
+        BenchmarkId::new("hit_test_storm_10k", "ui_bvh_f64"),
+        &tree,
+        |b, tree| {
+            b.iter(|| {
+                for &p in &pts {
+                    black_box(tree.hit_test_point(black_box(p), filter));
+                }
+            });
+        },
+    );
+
+    g.finish();
+}
+
+criterion_group!(benches, ui_box_tree, large_box_tree, deep_box_tree);
 criterion_main!(benches);
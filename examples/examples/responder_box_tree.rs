@@ -12,7 +12,7 @@
 use std::collections::HashMap;
 
 use kurbo::{Affine, Point, Rect};
-use understory_box_tree::{LocalNode, NodeFlags, NodeId, QueryFilter, Tree};
+use understory_box_tree::{HitPolicy, LocalNode, NodeFlags, NodeId, QueryFilter, Tree};
 use understory_event_state::hover::HoverState;
 use understory_responder::adapters::box_tree::{hits_for_rect, top_hit_for_point};
 use understory_responder::dispatcher;
@@ -29,6 +29,7 @@ fn main() {
         local_clip: None,
         z_index: 0,
         flags: NodeFlags::VISIBLE | NodeFlags::PICKABLE,
+        hit_policy: HitPolicy::Auto,
     };
     let root = bt.insert(None, root_local);
 
@@ -31,8 +31,9 @@
 //! Think of this as a scene and spatial index, not a layout system.
 //!
 //! This crate also does not model stacking contexts, opacity, or blend modes. It provides a single
-//! global z ordering (`z_index`) over boxes plus hit-testing and visibility queries. Higher-level
-//! code is expected to introduce groups, stacking semantics, and paint order if needed.
+//! global z ordering (`z_index`) over boxes plus hit-testing and visibility queries, and a paint
+//! order derived from the same ordering. Higher-level code is expected to introduce groups and
+//! nested stacking semantics if needed.
 //!
 //! ## Integration with Understory Index
 //!
@@ -55,6 +56,8 @@
 //! - [`LocalNode`]: per-node local data (bounds, transform, optional clip, z, flags).
 //!   See [`LocalNode::flags`] for visibility/picking/focusable controls.
 //! - [`NodeFlags`]: visibility, picking, and focusable controls.
+//! - [`HitPolicy`]: per-node hit semantics: pass-through (`pointer-events: none`), bounding
+//!   box only, or an alpha threshold checked by [`Tree::hit_test_point_with_alpha`].
 //! - [`NodeId`]: generational handle of a node.
 //! - [`QueryFilter`]: restricts hit/intersect results (visible/pickable/focusable).
 //!   See [`NodeFlags::VISIBLE`], [`NodeFlags::PICKABLE`], and [`NodeFlags::FOCUSABLE`].
@@ -64,10 +67,18 @@
 //! - [`Tree::set_local_transform`] / [`Tree::set_local_clip`] /
 //!   [`Tree::set_local_bounds`] / [`Tree::set_flags`]
 //! - [`Tree::commit`] → damage summary; updates world data and the spatial index.
-//! - [`Tree::hit_test_point`] and [`Tree::intersect_rect`].
+//! - [`Tree::apply_update`] applies a [`TreeUpdate`] of many inserts, updates, removals, and
+//!   z-order changes at once; [`TreeUpdate::diff`] computes one from two [`Tree::snapshot`]s.
+//! - [`Tree::hit_test_point`] and [`Tree::intersect_rect`]. A [`Hit`] carries the path from
+//!   the root to the hit node and the hit point in each path node's local coordinates.
+//! - [`Tree::paint_order`] / [`Tree::paint_order_in_rect`] list nodes back to front using the
+//!   same ordering as hit testing, so paint order and hit order cannot diverge.
 //! - [`Tree::z_index`] exposes the stacking order of a live [`NodeId`].
+//! - [`Tree::bring_to_front`] / [`Tree::send_to_back`] / [`Tree::raise_above`] reorder a node
+//!   among its siblings and renumber their `z_index` values densely.
 //! - [`Tree::parent_of`] returns the parent of a live [`NodeId`].
-//! - [`Tree::flags`] returns the [`NodeFlags`] of a live [`NodeId`].
+//! - [`Tree::flags`] returns the [`NodeFlags`] of a live [`NodeId`]; [`Tree::effective_flags`]
+//!   folds in visibility inherited from ancestors.
 //! - [`Tree::world_transform`] / [`Tree::world_bounds`]
 //!   expose the local→world transform and world-space AABB for a live [`NodeId`].
 //! - [`Tree::local_transform`] / [`Tree::local_bounds`] /
@@ -81,6 +92,9 @@
 //! - [`Tree::commit`] batches adds/updates/removals and produces coarse damage (added/removed AABBs and
 //!   old/new pairs for moved nodes). The reported rectangles may overlap and are not a minimal cover,
 //!   but are sufficient to bound a paint traversal in most UIs.
+//! - Changes that do not need a commit ([`Tree::set_z_index`], visibility changes via [`Tree::set_flags`],
+//!   and content changes signalled with [`Tree::invalidate`]) still add the affected bounds to the
+//!   damage returned by the next [`Tree::commit`].
 //! - World AABBs are loose under rotation/shear and rounded-rect clips are approximated by
 //!   their axis-aligned bounds for acceleration; precise hit-filtering is applied where cheap.
 //!
@@ -99,8 +113,11 @@ extern crate alloc;
 mod damage;
 mod tree;
 mod types;
+mod update;
 mod util;
+mod zorder;
 
 pub use damage::Damage;
 pub use tree::{Hit, QueryFilter, Tree};
-pub use types::{LocalNode, NodeFlags, NodeId};
+pub use types::{HitPolicy, LocalNode, NodeFlags, NodeId};
+pub use update::{NodeSnapshot, NodeUpsert, TreeUpdate};
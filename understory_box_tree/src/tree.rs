@@ -4,11 +4,13 @@
 //! Core tree implementation: structure, updates, queries.
 
 use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
 use kurbo::{Affine, Point, Rect, RoundedRect, Shape};
 use understory_index::{Backend, IndexGeneric, Key as AabbKey, backends::FlatVec};
 
 use crate::damage::Damage;
-use crate::types::{LocalNode, NodeFlags, NodeId};
+use crate::types::{HitPolicy, LocalNode, NodeFlags, NodeId};
+use crate::update::NodeSnapshot;
 use crate::util::{rect_to_aabb, transform_rect_bbox};
 
 /// Top-level region tree.
@@ -56,6 +58,8 @@ pub struct Tree<B: Backend<f64> = FlatVec<f64>> {
     pub(crate) index: IndexGeneric<f64, NodeId, B>,
     needs_commit: bool,
     dirty_roots: Vec<NodeId>,
+    /// Repaint damage from changes that take effect without a commit, reported by the next commit.
+    pending_damage: Vec<Rect>,
 }
 
 impl<B: Backend<f64> + core::fmt::Debug> core::fmt::Debug for Tree<B> {
@@ -70,6 +74,7 @@ impl<B: Backend<f64> + core::fmt::Debug> core::fmt::Debug for Tree<B> {
             .field("epoch", &self.epoch)
             .field("index", &self.index)
             .field("needs_commit", &self.needs_commit)
+            .field("pending_damage", &self.pending_damage.len())
             .finish_non_exhaustive()
     }
 }
@@ -90,12 +95,36 @@ pub struct Hit {
     pub node: NodeId,
     /// Path from root to node (inclusive).
     pub path: Vec<NodeId>,
+    /// The hit point in the local coordinates of each node in `path`, in the same order.
+    ///
+    /// Handlers along a routed path can use their entry directly instead of inverting world
+    /// transforms again, for example to anchor a drag inside a nested, zoomed view.
+    pub local_points: Vec<Point>,
+}
+
+impl Hit {
+    /// The hit point in the local coordinates of the matched node.
+    #[must_use]
+    pub fn local_point(&self) -> Point {
+        self.local_points.last().copied().unwrap_or(Point::ORIGIN)
+    }
+
+    /// The hit point in the local coordinates of `id`, if it is on the path.
+    #[must_use]
+    pub fn local_point_in(&self, id: NodeId) -> Option<Point> {
+        let index = self.path.iter().position(|node| *node == id)?;
+        self.local_points.get(index).copied()
+    }
 }
 
 /// Filters applied during hit testing and rectangle intersection.
 ///
 /// Used by [`Tree::hit_test_point`] and [`Tree::intersect_rect`] to restrict
 /// which nodes participate in queries.
+///
+/// Queries match against a node's [effective flags](Tree::effective_flags), so a node
+/// under an invisible ancestor is excluded by [`QueryFilter::visible`] and
+/// [`QueryFilter::pickable`] even if its own flags are set.
 #[derive(Clone, Copy, Debug)]
 pub struct QueryFilter {
     /// Bitfield of required node flags. Only nodes containing all these flags will be included.
@@ -138,6 +167,12 @@ impl QueryFilter {
     pub fn matches(&self, node_flags: NodeFlags) -> bool {
         node_flags.contains(self.required_flags)
     }
+
+    /// Whether matching depends on the node (and its ancestors) being visible.
+    fn requires_visibility(&self) -> bool {
+        self.required_flags
+            .intersects(NodeFlags::VISIBLE | NodeFlags::PICKABLE)
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -202,6 +237,7 @@ impl Tree {
             index: IndexGeneric::new(),
             needs_commit: false,
             dirty_roots: Vec::new(),
+            pending_damage: Vec::new(),
         }
     }
 }
@@ -217,6 +253,7 @@ impl<B: Backend<f64>> Tree<B> {
             index: IndexGeneric::with_backend(backend),
             needs_commit: false,
             dirty_roots: Vec::new(),
+            pending_damage: Vec::new(),
         }
     }
 
@@ -247,6 +284,35 @@ impl<B: Backend<f64>> Tree<B> {
         self.dirty_roots.push(id);
     }
 
+    /// Record the current world bounds of `id` (and optionally its descendants) as damage.
+    ///
+    /// Nodes that have not been committed yet are skipped; their bounds are reported when
+    /// they are first added to the spatial index.
+    fn push_paint_damage(&mut self, id: NodeId, subtree: bool) {
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let node = self.node(id);
+            let bounds = node.world.world_bounds;
+            if node.index_key.is_some() && bounds.width() > 0.0 && bounds.height() > 0.0 {
+                self.pending_damage.push(bounds);
+            }
+            if subtree {
+                stack.extend_from_slice(&self.node(id).children);
+            }
+        }
+    }
+
+    /// Mark a live node's contents as changed without changing its geometry.
+    ///
+    /// Use this when what a node paints changes (for example a new color or image) but its
+    /// bounds do not. The node's world bounds are reported in the [`Damage`] returned by the
+    /// next [`Tree::commit`]. Stale identifiers are ignored.
+    pub fn invalidate(&mut self, id: NodeId) {
+        if self.is_alive(id) {
+            self.push_paint_damage(id, false);
+        }
+    }
+
     /// Insert a new node as a child of `parent` (or as a root if `None`).
     ///
     /// The returned [`NodeId`] becomes live immediately, but world-space data
@@ -363,11 +429,13 @@ impl<B: Backend<f64>> Tree<B> {
     /// Update z index.
     ///
     /// The change takes effect immediately and does not require a [`Tree::commit`].
+    /// The node's bounds are reported as damage by the next commit.
     pub fn set_z_index(&mut self, id: NodeId, z: i32) {
         if let Some(n) = self.node_opt_mut(id)
             && n.local.z_index != z
         {
             n.local.z_index = z;
+            self.push_paint_damage(id, false);
         }
     }
 
@@ -392,6 +460,8 @@ impl<B: Backend<f64>> Tree<B> {
     /// Update node flags.
     ///
     /// The change takes effect immediately and does not require a [`Tree::commit`].
+    /// If [`NodeFlags::VISIBLE`] changes, the bounds of the node and its descendants are
+    /// reported as damage by the next commit.
     pub fn set_flags(&mut self, id: NodeId, flags: NodeFlags) {
         let Some(n) = self.node_opt_mut(id) else {
             return;
@@ -399,7 +469,21 @@ impl<B: Backend<f64>> Tree<B> {
         if n.local.flags == flags {
             return;
         }
+        let visibility_changed = (n.local.flags ^ flags).contains(NodeFlags::VISIBLE);
         n.local.flags = flags;
+        if visibility_changed {
+            self.push_paint_damage(id, true);
+        }
+    }
+
+    /// Update how hit testing treats the node.
+    ///
+    /// The change takes effect immediately and does not require a [`Tree::commit`]. It does not
+    /// affect painting, so no damage is reported.
+    pub fn set_hit_policy(&mut self, id: NodeId, policy: HitPolicy) {
+        if let Some(n) = self.node_opt_mut(id) {
+            n.local.hit_policy = policy;
+        }
     }
 
     /// Return the world transform for a live node as of the last [`Tree::commit`].
@@ -500,12 +584,15 @@ impl<B: Backend<f64>> Tree<B> {
     ///
     /// Note: not all updates require a commit. Changes to [`LocalNode`] flags or
     /// `z_index` take effect immediately (they do not affect world-space data or
-    /// the spatial index).
+    /// the spatial index). The repaint damage they cause, along with any from
+    /// [`Tree::invalidate`], is still collected and returned by the next commit.
     pub fn commit(&mut self) -> Damage {
+        let mut damage = Damage {
+            dirty_rects: core::mem::take(&mut self.pending_damage),
+        };
         if !self.needs_commit {
-            return Damage::default();
+            return damage;
         }
-        let mut damage = Damage::default();
         let mut starts = core::mem::take(&mut self.dirty_roots);
         starts.retain(|id| self.is_alive(*id));
         starts.sort_by_key(|id| (id.1, id.0));
@@ -563,19 +650,42 @@ impl<B: Backend<f64>> Tree<B> {
     }
 
     /// Hit test a world-space point and, if any node matches, return the
-    /// topmost node and its path to root as a [`Hit`].
+    /// topmost node, its path from the root, and the point in each path node's local
+    /// coordinates as a [`Hit`].
     ///
     /// - `point` is interpreted in world coordinates.
-    /// - Nodes must satisfy the [`QueryFilter`] and contain the point within their
+    /// - Nodes must satisfy the [`QueryFilter`] (against their
+    ///   [effective flags](Tree::effective_flags)) and contain the point within their
     ///   world-space bounds and clip to be eligible.
     /// - Among candidates, higher `z_index` wins; if `z_index` ties, deeper nodes
     ///   in the tree win; if that also ties, the newer [`NodeId`] wins.
     ///
+    /// - Each node's [`HitPolicy`] refines the bounds and clip test; nodes with
+    ///   [`HitPolicy::AlphaMask`] are treated as opaque here, see
+    ///   [`Tree::hit_test_point_with_alpha`].
+    ///
     /// This tie-break is intentionally deterministic for now. In the future this
     /// may be made configurable (for example via a `TieBreakPolicy`).
     pub fn hit_test_point(&self, point: Point, filter: QueryFilter) -> Option<Hit> {
+        self.hit_test_point_with_alpha(point, filter, |_, _| 1.0)
+    }
+
+    /// Hit test like [`Tree::hit_test_point`], sampling content alpha for nodes with
+    /// [`HitPolicy::AlphaMask`].
+    ///
+    /// `alpha` receives the candidate node and the point in its local coordinates and returns
+    /// the content's alpha there in `0.0..=1.0`, for example from a rasterized mask of the
+    /// node's icon. Transparent areas below the threshold let the point fall through to nodes
+    /// beneath. The callback is only invoked for candidates that would otherwise be the
+    /// topmost hit so far.
+    pub fn hit_test_point_with_alpha(
+        &self,
+        point: Point,
+        filter: QueryFilter,
+        mut alpha: impl FnMut(NodeId, Point) -> f32,
+    ) -> Option<Hit> {
         self.debug_assert_committed();
-        let mut best: Option<(NodeId, i32, u16)> = None;
+        let mut best: Option<StackKey> = None;
         self.index.visit_point(point.x, point.y, |_, id| {
             // The spatial index provides a coarse world-AABB candidate set. Everything below is
             // precise filtering in local coordinates (bounds/clips) plus deterministic tie-breaks.
@@ -585,19 +695,29 @@ impl<B: Backend<f64>> Tree<B> {
             if node.generation != id.1 || !filter.matches(node.local.flags) {
                 return;
             }
+            let check_visibility = filter.requires_visibility();
+            if check_visibility && !node.local.flags.contains(NodeFlags::VISIBLE) {
+                return;
+            }
 
             // Finely test whether `point` is within the node's bounds and the node's own clip.
+            let policy = node.local.hit_policy;
+            if policy == HitPolicy::None {
+                return;
+            }
             let local_point = node.world.world_transform_inverse * point;
             if !node.local.local_bounds.contains(local_point) {
                 return;
             }
-            if let Some(clip) = node.local.local_clip
+            if policy != HitPolicy::BoundingBox
+                && let Some(clip) = node.local.local_clip
                 && !clip.contains(local_point)
             {
                 return;
             }
 
-            // Walk ancestors towards the root checking their clips for precise hit filtering.
+            // Walk ancestors towards the root checking their clips and visibility for precise
+            // hit filtering.
             //
             // This is intentionally only done for candidates that pass the local bounds/clip
             // checks, since ancestor traversal is comparatively expensive.
@@ -608,6 +728,9 @@ impl<B: Backend<f64>> Tree<B> {
                     parent.generation, parent_id.1,
                     "parent slot generation mismatch"
                 );
+                if check_visibility && !parent.local.flags.contains(NodeFlags::VISIBLE) {
+                    return;
+                }
                 if let Some(clip) = parent.local.local_clip {
                     let parent_local_point = parent.world.world_transform_inverse * point;
                     if !clip.contains(parent_local_point) {
@@ -617,28 +740,74 @@ impl<B: Backend<f64>> Tree<B> {
                 current = parent.parent;
             }
 
-            let depth = node.world.depth;
-            let z = node.local.z_index;
-            match best {
-                None => best = Some((id, z, depth)),
-                Some((id_best, z_best, depth_best)) => {
-                    if z > z_best
-                        || (z == z_best
-                            && (depth > depth_best
-                                || (depth == depth_best && id_is_newer(id, id_best))))
-                    {
-                        best = Some((id, z, depth));
-                    }
-                }
+            let key = StackKey::of(id, node);
+            if best.is_some_and(|best| !key.is_above(&best)) {
+                return;
+            }
+            if let HitPolicy::AlphaMask(threshold) = policy
+                && alpha(id, local_point) < threshold
+            {
+                return;
             }
+            best = Some(key);
         });
 
-        best.map(|(node, _, _)| Hit {
-            node,
-            path: self.path_to_root(node),
+        best.map(|StackKey { id: node, .. }| {
+            let path = self.path_to_root(node);
+            let local_points = path
+                .iter()
+                .map(|id| self.node(*id).world.world_transform_inverse * point)
+                .collect();
+            Hit {
+                node,
+                path,
+                local_points,
+            }
         })
     }
 
+    /// Return live nodes satisfying the [`QueryFilter`] in paint order (back to front).
+    ///
+    /// Nodes are ordered by the same rule [`Tree::hit_test_point`] uses to pick the topmost
+    /// node: ascending `z_index`, then ascending depth, then older [`NodeId`]s first. Painting in
+    /// this order therefore guarantees that the last node painted at a point is the one a hit
+    /// test there would return.
+    pub fn paint_order(&self, filter: QueryFilter) -> Vec<NodeId> {
+        self.debug_assert_committed();
+        let mut keys: Vec<StackKey> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, slot)| {
+                let node = slot.as_ref()?;
+                if !self.matches_effective(node, filter) {
+                    return None;
+                }
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    reason = "NodeId uses 32-bit indices by design."
+                )]
+                let id = NodeId::new(idx as u32, node.generation);
+                Some(StackKey::of(id, node))
+            })
+            .collect();
+        keys.sort_unstable_by(StackKey::paint_cmp);
+        keys.into_iter().map(|key| key.id).collect()
+    }
+
+    /// Return live nodes intersecting a world-space rectangle in paint order (back to front).
+    ///
+    /// This is [`Tree::intersect_rect`] sorted like [`Tree::paint_order`], suitable for
+    /// repainting a damaged region.
+    pub fn paint_order_in_rect(&self, rect: Rect, filter: QueryFilter) -> Vec<NodeId> {
+        let mut keys: Vec<StackKey> = self
+            .intersect_rect(rect, filter)
+            .map(|id| StackKey::of(id, self.node(id)))
+            .collect();
+        keys.sort_unstable_by(StackKey::paint_cmp);
+        keys.into_iter().map(|key| key.id).collect()
+    }
+
     /// Iterate live nodes whose world-space bounds intersect a world-space rectangle.
     ///
     /// Edges of the rectangle and bounding boxes are included in the intersection, meaning that a
@@ -662,7 +831,7 @@ impl<B: Backend<f64>> Tree<B> {
                 let Some(node) = self.nodes[id.idx()].as_ref() else {
                     return false;
                 };
-                filter.matches(node.local.flags)
+                self.matches_effective(node, filter)
             })
     }
 
@@ -688,11 +857,47 @@ impl<B: Backend<f64>> Tree<B> {
                 let Some(node) = self.nodes[id.idx()].as_ref() else {
                     return false;
                 };
-                filter.matches(node.local.flags)
+                self.matches_effective(node, filter)
             })
     }
 }
 
+/// Stacking position of a node, shared by hit testing and paint order.
+#[derive(Clone, Copy, Debug)]
+struct StackKey {
+    id: NodeId,
+    z: i32,
+    depth: u16,
+}
+
+impl StackKey {
+    fn of(id: NodeId, node: &Node) -> Self {
+        Self {
+            id,
+            z: node.local.z_index,
+            depth: node.world.depth,
+        }
+    }
+
+    /// Higher `z_index` wins; if `z_index` ties, deeper wins; if that also ties, newer wins.
+    fn is_above(&self, other: &Self) -> bool {
+        self.z > other.z
+            || (self.z == other.z
+                && (self.depth > other.depth
+                    || (self.depth == other.depth && id_is_newer(self.id, other.id))))
+    }
+
+    fn paint_cmp(a: &Self, b: &Self) -> Ordering {
+        if a.is_above(b) {
+            Ordering::Greater
+        } else if b.is_above(a) {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
 #[inline]
 fn id_is_newer(a: NodeId, b: NodeId) -> bool {
     (a.1 > b.1) || (a.1 == b.1 && a.0 > b.0)
@@ -747,6 +952,37 @@ impl<B: Backend<f64>> Tree<B> {
             .map(|node| node.local.flags)
     }
 
+    /// Returns the hit policy of a node if the identifier is live.
+    #[must_use]
+    pub fn hit_policy(&self, id: NodeId) -> Option<HitPolicy> {
+        if !self.is_alive(id) {
+            return None;
+        }
+        self.nodes
+            .get(id.idx())
+            .and_then(|slot| slot.as_ref())
+            .map(|node| node.local.hit_policy)
+    }
+
+    /// Returns the effective flags of a node if the identifier is live.
+    ///
+    /// [`NodeFlags::VISIBLE`] propagates down the tree: a node is only effectively visible if
+    /// every ancestor is visible too. A node that is not effectively visible is not effectively
+    /// [pickable](NodeFlags::PICKABLE) either, so rendering and hit testing agree on what is
+    /// interactive. Picking itself is not inherited: a non-pickable container may hold pickable
+    /// children. [`NodeFlags::FOCUSABLE`] is reported as set on the node itself.
+    ///
+    /// These are the flags that [`QueryFilter`] is matched against.
+    ///
+    /// Like [`Tree::flags`], this reflects changes immediately and does not require a
+    /// [`Tree::commit`].
+    pub fn effective_flags(&self, id: NodeId) -> Option<NodeFlags> {
+        if !self.is_alive(id) {
+            return None;
+        }
+        Some(self.effective_flags_of(self.node(id)))
+    }
+
     /// Get the next node in depth-first traversal order.
     ///
     /// Returns `None` if no next node exists or if the current node is stale.
@@ -779,6 +1015,46 @@ impl<B: Backend<f64>> Tree<B> {
         &self.node(id).children
     }
 
+    /// Return the parent and local state of every live node, for diffing with
+    /// [`TreeUpdate::diff`].
+    ///
+    /// [`TreeUpdate::diff`]: crate::TreeUpdate::diff
+    pub fn snapshot(&self) -> Vec<NodeSnapshot> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, slot)| {
+                let node = slot.as_ref()?;
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    reason = "NodeId uses 32-bit indices by design."
+                )]
+                let id = NodeId::new(idx as u32, node.generation);
+                Some(NodeSnapshot {
+                    id,
+                    parent: node.parent,
+                    local: node.local.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Return the live nodes without a parent, in slot order.
+    pub(crate) fn roots(&self) -> Vec<NodeId> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, slot)| {
+                let node = slot.as_ref().filter(|node| node.parent.is_none())?;
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    reason = "NodeId uses 32-bit indices by design."
+                )]
+                Some(NodeId::new(idx as u32, node.generation))
+            })
+            .collect()
+    }
+
     fn next_in_order(&self, current: NodeId) -> Option<NodeId> {
         let children = &self.node(current).children;
         if let Some(&first_child) = children.first()
@@ -858,6 +1134,36 @@ impl<B: Backend<f64>> Tree<B> {
         self.node_mut(id).parent = None;
     }
 
+    fn effective_flags_of(&self, node: &Node) -> NodeFlags {
+        let mut flags = node.local.flags;
+        let mut current = node.parent;
+        while let Some(parent_id) = current {
+            if !flags.contains(NodeFlags::VISIBLE) {
+                break;
+            }
+            let parent = self.node(parent_id);
+            if !parent.local.flags.contains(NodeFlags::VISIBLE) {
+                flags.remove(NodeFlags::VISIBLE);
+            }
+            current = parent.parent;
+        }
+        if !flags.contains(NodeFlags::VISIBLE) {
+            flags.remove(NodeFlags::PICKABLE);
+        }
+        flags
+    }
+
+    /// Check a node against a filter, walking ancestors only if visibility matters.
+    fn matches_effective(&self, node: &Node, filter: QueryFilter) -> bool {
+        if !filter.matches(node.local.flags) {
+            return false;
+        }
+        if !filter.requires_visibility() {
+            return true;
+        }
+        filter.matches(self.effective_flags_of(node))
+    }
+
     fn path_to_root(&self, mut id: NodeId) -> Vec<NodeId> {
         let mut out = Vec::new();
         loop {
@@ -1392,6 +1698,134 @@ mod tests {
         assert!(miss.is_none());
     }
 
+    #[test]
+    fn nested_transforms_are_inverted_for_hits() {
+        let mut tree = Tree::new();
+        let root = tree.insert(
+            None,
+            LocalNode {
+                local_transform: Affine::translate((100.0, 100.0)) * Affine::scale(2.0),
+                ..Default::default()
+            },
+        );
+        let child = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 20.0, 10.0),
+                local_transform: Affine::rotate(90_f64.to_radians()),
+                ..Default::default()
+            },
+        );
+        let _ = tree.commit();
+
+        // The child spans x in [-20, 0] and y in [0, 40] relative to (100, 100) after
+        // rotation and scaling; its world AABB conservatively contains that shape.
+        let bounds = tree.world_bounds(child).unwrap();
+        assert!(bounds.contains(Point::new(81.0, 139.0)));
+        assert!(bounds.contains(Point::new(99.0, 101.0)));
+
+        let hit = tree.hit_test_point(Point::new(90.0, 130.0), QueryFilter::new());
+        assert_eq!(hit.map(|h| h.node), Some(child));
+        // Inside the unrotated local rectangle, but not the rotated one.
+        let miss = tree.hit_test_point(Point::new(130.0, 110.0), QueryFilter::new());
+        assert!(miss.is_none());
+    }
+
+    #[test]
+    fn hit_reports_local_point_for_each_path_node() {
+        let mut tree = Tree::new();
+        let root = tree.insert(
+            None,
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 200.0, 200.0),
+                ..Default::default()
+            },
+        );
+        let zoomed = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 100.0, 100.0),
+                local_transform: Affine::translate((50.0, 20.0)) * Affine::scale(0.5),
+                ..Default::default()
+            },
+        );
+        let leaf = tree.insert(
+            Some(zoomed),
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 20.0, 20.0),
+                local_transform: Affine::translate((10.0, 10.0)),
+                ..Default::default()
+            },
+        );
+        let _ = tree.commit();
+
+        let hit = tree
+            .hit_test_point(Point::new(60.0, 30.0), QueryFilter::new())
+            .unwrap();
+        assert_eq!(hit.path, [root, zoomed, leaf]);
+        assert_eq!(
+            hit.local_points,
+            [
+                Point::new(60.0, 30.0),
+                Point::new(20.0, 20.0),
+                Point::new(10.0, 10.0)
+            ]
+        );
+        assert_eq!(hit.local_point(), Point::new(10.0, 10.0));
+        assert_eq!(hit.local_point_in(zoomed), Some(Point::new(20.0, 20.0)));
+        let other = tree.insert(None, LocalNode::default());
+        assert_eq!(hit.local_point_in(other), None);
+    }
+
+    #[test]
+    fn hit_policies_refine_hits() {
+        let mut tree = Tree::new();
+        let content = tree.insert(
+            None,
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 100.0, 100.0),
+                ..Default::default()
+            },
+        );
+        let icon = tree.insert(
+            None,
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 40.0, 40.0),
+                local_clip: Some(RoundedRect::new(0.0, 0.0, 40.0, 40.0, 20.0)),
+                z_index: 1,
+                ..Default::default()
+            },
+        );
+        let _ = tree.commit();
+        let corner = Point::new(2.0, 2.0);
+        let center = Point::new(20.0, 20.0);
+        let hit_node = |tree: &Tree, point| {
+            tree.hit_test_point(point, QueryFilter::new())
+                .map(|hit| hit.node)
+        };
+        assert_eq!(hit_node(&tree, corner), Some(content));
+        assert_eq!(hit_node(&tree, center), Some(icon));
+
+        tree.set_hit_policy(icon, HitPolicy::BoundingBox);
+        assert_eq!(hit_node(&tree, corner), Some(icon));
+
+        tree.set_hit_policy(icon, HitPolicy::None);
+        assert_eq!(tree.hit_policy(icon), Some(HitPolicy::None));
+        assert_eq!(hit_node(&tree, center), Some(content));
+
+        // The alpha callback sees local points; only the left half of the icon is opaque.
+        tree.set_hit_policy(icon, HitPolicy::AlphaMask(0.5));
+        let left_half = |_: NodeId, local: Point| if local.x < 20.0 { 1.0 } else { 0.0 };
+        let hit_with_alpha = |point| {
+            tree.hit_test_point_with_alpha(point, QueryFilter::new(), left_half)
+                .map(|hit| hit.node)
+        };
+        assert_eq!(hit_with_alpha(Point::new(15.0, 20.0)), Some(icon));
+        assert_eq!(hit_with_alpha(Point::new(25.0, 20.0)), Some(content));
+        // Without an alpha source the icon is treated as opaque.
+        assert_eq!(hit_node(&tree, Point::new(25.0, 20.0)), Some(icon));
+    }
+
     #[test]
     fn child_clip_intersects_with_parent_clip() {
         let mut tree = Tree::new();
@@ -2327,4 +2761,185 @@ mod tests {
         let prev = tree.prev_depth_first(a).unwrap();
         assert_eq!(prev, root);
     }
+
+    #[test]
+    fn visibility_is_inherited() {
+        let mut tree = Tree::new();
+        let root = tree.insert(
+            None,
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 100.0, 100.0),
+                ..Default::default()
+            },
+        );
+        let group = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 50.0, 50.0),
+                ..Default::default()
+            },
+        );
+        let leaf = tree.insert(
+            Some(group),
+            LocalNode {
+                local_bounds: Rect::new(10.0, 10.0, 20.0, 20.0),
+                flags: NodeFlags::all(),
+                ..Default::default()
+            },
+        );
+        let _ = tree.commit();
+
+        let pickable = QueryFilter::new().visible().pickable();
+        let hit = |tree: &Tree| tree.hit_test_point(Point::new(15.0, 15.0), pickable);
+        assert_eq!(hit(&tree).unwrap().node, leaf);
+
+        // Disabling picking on the group does not affect its children.
+        tree.set_flags(group, NodeFlags::VISIBLE);
+        assert_eq!(tree.effective_flags(leaf), Some(NodeFlags::all()));
+        assert_eq!(hit(&tree).unwrap().node, leaf);
+
+        // Hiding the group hides its descendants too, which also makes them unpickable.
+        tree.set_flags(group, NodeFlags::PICKABLE);
+        assert_eq!(tree.flags(leaf), Some(NodeFlags::all()));
+        assert_eq!(tree.effective_flags(leaf), Some(NodeFlags::FOCUSABLE));
+        assert_eq!(tree.effective_flags(group), Some(NodeFlags::empty()));
+        assert_eq!(hit(&tree).unwrap().node, root);
+        let visible: Vec<NodeId> = tree
+            .intersect_rect(
+                Rect::new(0.0, 0.0, 100.0, 100.0),
+                QueryFilter::new().visible(),
+            )
+            .collect();
+        assert!(set_equality(&visible, &[root]));
+
+        // Focusability is not inherited.
+        let focusable: Vec<NodeId> = tree
+            .containing_point(Point::new(15.0, 15.0), QueryFilter::new().focusable())
+            .collect();
+        assert!(set_equality(&focusable, &[leaf]));
+
+        tree.remove(leaf);
+        assert_eq!(tree.effective_flags(leaf), None);
+    }
+
+    #[test]
+    fn paint_order_matches_hit_order() {
+        let mut tree = Tree::new();
+        let root = tree.insert(
+            None,
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 100.0, 100.0),
+                ..Default::default()
+            },
+        );
+        let raised = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 50.0, 50.0),
+                z_index: 5,
+                ..Default::default()
+            },
+        );
+        let a = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 50.0, 50.0),
+                ..Default::default()
+            },
+        );
+        let b = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 50.0, 50.0),
+                ..Default::default()
+            },
+        );
+        let far = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(80.0, 80.0, 90.0, 90.0),
+                ..Default::default()
+            },
+        );
+        let _ = tree.commit();
+
+        let order = tree.paint_order(QueryFilter::new());
+        assert_eq!(order, [root, a, b, far, raised]);
+        let hit = tree.hit_test_point(Point::new(10.0, 10.0), QueryFilter::new());
+        assert_eq!(hit.unwrap().node, *order.last().unwrap());
+
+        // z changes apply immediately to both hit testing and paint order.
+        tree.set_z_index(raised, -1);
+        let order = tree.paint_order_in_rect(Rect::new(0.0, 0.0, 20.0, 20.0), QueryFilter::new());
+        assert_eq!(order, [raised, root, a, b]);
+        let hit = tree.hit_test_point(Point::new(10.0, 10.0), QueryFilter::new());
+        assert_eq!(hit.unwrap().node, b);
+
+        tree.set_flags(b, NodeFlags::empty());
+        let order = tree.paint_order(QueryFilter::new().visible());
+        assert_eq!(order, [raised, root, a, far]);
+    }
+
+    #[test]
+    fn immediate_changes_report_damage_on_commit() {
+        let mut tree = Tree::new();
+        let root = tree.insert(
+            None,
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 100.0, 100.0),
+                ..Default::default()
+            },
+        );
+        let child = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(10.0, 10.0, 20.0, 20.0),
+                ..Default::default()
+            },
+        );
+        let _ = tree.commit();
+
+        // Picking does not affect painting.
+        tree.set_flags(child, NodeFlags::VISIBLE);
+        assert!(tree.commit().dirty_rects.is_empty());
+
+        tree.set_z_index(child, 3);
+        assert!(!tree.needs_commit());
+        assert_eq!(
+            tree.commit().dirty_rects,
+            [Rect::new(10.0, 10.0, 20.0, 20.0)]
+        );
+        assert!(tree.commit().dirty_rects.is_empty());
+
+        tree.invalidate(root);
+        assert_eq!(
+            tree.commit().dirty_rects,
+            [Rect::new(0.0, 0.0, 100.0, 100.0)]
+        );
+
+        // Hiding a node damages its whole subtree.
+        tree.set_flags(root, NodeFlags::empty());
+        let damage = tree.commit();
+        assert_eq!(damage.dirty_rects.len(), 2);
+        assert_eq!(damage.union_rect(), Some(Rect::new(0.0, 0.0, 100.0, 100.0)));
+
+        // Nodes that were never committed are reported by the commit that adds them.
+        let fresh = tree.insert(
+            Some(root),
+            LocalNode {
+                local_bounds: Rect::new(50.0, 50.0, 60.0, 60.0),
+                ..Default::default()
+            },
+        );
+        tree.invalidate(fresh);
+        tree.set_z_index(fresh, 1);
+        let damage = tree.commit();
+        assert_eq!(damage.union_rect(), Some(Rect::new(50.0, 50.0, 60.0, 60.0)));
+
+        let stale = fresh;
+        tree.remove(stale);
+        let _ = tree.commit();
+        tree.invalidate(stale);
+        assert!(tree.commit().dirty_rects.is_empty());
+    }
 }
@@ -66,6 +66,35 @@ impl Default for NodeFlags {
     }
 }
 
+/// How hit testing decides whether a point over a node hits it.
+///
+/// Set per node with [`LocalNode::hit_policy`] or
+/// [`Tree::set_hit_policy`](crate::Tree::set_hit_policy). Policies only change whether the node
+/// itself is hit; descendants and ancestor clips are unaffected, and [`NodeFlags::PICKABLE`]
+/// still has to be set when picking is filtered.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum HitPolicy {
+    /// Hit inside the local bounds and the node's own clip.
+    #[default]
+    Auto,
+    /// Never hit this node, so points reach the nodes beneath it (`pointer-events: none`).
+    ///
+    /// Unlike clearing [`NodeFlags::PICKABLE`], this applies to every query filter.
+    None,
+    /// Hit anywhere inside the local bounds, ignoring the node's own clip.
+    ///
+    /// Useful for enlarging the target of a node whose clip rounds off its corners.
+    BoundingBox,
+    /// Like [`HitPolicy::Auto`], then only hit where the content's alpha is at least the
+    /// threshold in `0.0..=1.0`.
+    ///
+    /// Alpha is looked up with the callback given to
+    /// [`Tree::hit_test_point_with_alpha`](crate::Tree::hit_test_point_with_alpha), typically
+    /// sampling a rasterized mask of an icon or picture. Other queries treat the content as
+    /// opaque.
+    AlphaMask(f32),
+}
+
 /// Local geometry for a node.
 #[derive(Clone, Debug)]
 pub struct LocalNode {
@@ -111,8 +140,14 @@ pub struct LocalNode {
     /// - [`NodeFlags::PICKABLE`] is consulted by hit testing.
     /// - [`NodeFlags::FOCUSABLE`] is consulted by focus/navigation layers.
     ///
+    /// Clearing [`NodeFlags::VISIBLE`] hides the whole subtree: descendants are excluded from
+    /// visible and pickable queries even if their own flags are set.
+    /// See [`Tree::effective_flags`](crate::Tree::effective_flags).
+    ///
     /// Flags do not affect layout; they only influence queries and higher-level behavior.
     pub flags: NodeFlags,
+    /// How hit testing decides whether a point inside the node's bounds hits it.
+    pub hit_policy: HitPolicy,
 }
 
 impl Default for LocalNode {
@@ -124,6 +159,7 @@ impl Default for LocalNode {
             local_clip: None,
             z_index: 0,
             flags: NodeFlags::default(),
+            hit_policy: HitPolicy::Auto,
         }
     }
 }
@@ -0,0 +1,368 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Bulk updates: apply many node changes at once and diff local-state snapshots.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use understory_index::Backend;
+
+use crate::tree::Tree;
+use crate::types::{LocalNode, NodeId};
+
+/// A node to insert or update as part of a [`TreeUpdate`].
+#[derive(Clone, Debug)]
+pub enum NodeUpsert {
+    /// Insert a new node under `parent` (or as a root if `None`).
+    Insert {
+        /// Placeholder id that later inserts in the same update can name as their `parent`.
+        ///
+        /// The tree allocates the real id; [`TreeUpdate::diff`] uses the node's id from the
+        /// `after` snapshot here.
+        key: Option<NodeId>,
+        /// Parent of the new node; must be live or the `key` of an earlier insert.
+        parent: Option<NodeId>,
+        /// Local state of the new node.
+        local: LocalNode,
+    },
+    /// Replace the local state of a live node. Stale identifiers are ignored.
+    Update {
+        /// Node to update.
+        id: NodeId,
+        /// New local state.
+        local: LocalNode,
+    },
+}
+
+/// The parent and local state of a node, as returned by [`Tree::snapshot`].
+#[derive(Clone, Debug)]
+pub struct NodeSnapshot {
+    /// The node.
+    pub id: NodeId,
+    /// Parent of the node, or `None` for a root.
+    pub parent: Option<NodeId>,
+    /// Local state of the node.
+    pub local: LocalNode,
+}
+
+/// A batch of node changes applied with [`Tree::apply_update`].
+///
+/// Build one by hand, or compute it from two snapshots with [`TreeUpdate::diff`].
+#[derive(Clone, Debug, Default)]
+pub struct TreeUpdate {
+    /// Nodes to insert or update.
+    pub upserts: Vec<NodeUpsert>,
+    /// Nodes to remove, together with their subtrees.
+    pub removals: Vec<NodeId>,
+    /// Nodes whose `z_index` changes and nothing else.
+    ///
+    /// These take effect without a commit, like [`Tree::set_z_index`].
+    pub reorders: Vec<(NodeId, i32)>,
+}
+
+impl TreeUpdate {
+    /// Returns `true` if the update contains no changes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.upserts.is_empty() && self.removals.is_empty() && self.reorders.is_empty()
+    }
+
+    /// Compute the update that turns the `before` snapshot into `after`.
+    ///
+    /// `before` is usually [`Tree::snapshot`] of the tree the update is applied to; `after` may
+    /// also come from a layout pass. Every parent named in `after` must itself be in `after`.
+    ///
+    /// - Nodes only in `before` become removals.
+    /// - Nodes only in `after` become [`NodeUpsert::Insert`]s, ordered so parents come first.
+    /// - Nodes whose parent changed are removed and inserted again under the new parent, as
+    ///   are their descendants in `after`, since removal takes the whole subtree.
+    /// - Nodes whose `z_index` is the only change become reorders.
+    /// - Nodes with any other change become [`NodeUpsert::Update`]s.
+    ///
+    /// Inserted nodes get new ids from the tree; their ids in `after` only serve as the
+    /// inserts' `key`s. [`Tree::apply_update`] returns the new ids in insert order.
+    #[must_use]
+    pub fn diff(before: &[NodeSnapshot], after: &[NodeSnapshot]) -> Self {
+        let key = |id: NodeId| (id.0, id.1);
+        let before_index: BTreeMap<_, &NodeSnapshot> =
+            before.iter().map(|node| (key(node.id), node)).collect();
+        let after_index: BTreeMap<_, usize> = after
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (key(node.id), i))
+            .collect();
+        let parent_index = |node: &NodeSnapshot| {
+            node.parent
+                .and_then(|parent| after_index.get(&key(parent)).copied())
+        };
+
+        // Whether each node in `after` has to be (re)inserted: it is new, it moved, or an
+        // ancestor in `after` is (re)inserted.
+        let mut reinsert: Vec<Option<bool>> = vec![None; after.len()];
+        for i in 0..after.len() {
+            let mut chain = Vec::new();
+            let mut current = i;
+            let result = loop {
+                if let Some(known) = reinsert[current] {
+                    break known;
+                }
+                let node = &after[current];
+                let moved = before_index
+                    .get(&key(node.id))
+                    .is_none_or(|previous| previous.parent != node.parent);
+                chain.push(current);
+                if moved {
+                    break true;
+                }
+                match parent_index(node) {
+                    // A malformed snapshot with a parent cycle stops here.
+                    Some(parent) if chain.len() <= after.len() => current = parent,
+                    _ => break false,
+                }
+            };
+            for node in chain {
+                reinsert[node] = Some(result);
+            }
+        }
+        let reinsert: Vec<bool> = reinsert.into_iter().map(|r| r == Some(true)).collect();
+
+        let mut update = Self::default();
+        let mut emitted = vec![false; after.len()];
+        for (i, node) in after.iter().enumerate() {
+            if reinsert[i] {
+                // Emit not-yet-inserted ancestors first so every parent key is known.
+                let mut pending = vec![i];
+                let mut current = i;
+                while let Some(parent) = parent_index(&after[current]) {
+                    if !reinsert[parent] || emitted[parent] || pending.contains(&parent) {
+                        break;
+                    }
+                    pending.push(parent);
+                    current = parent;
+                }
+                for &j in pending.iter().rev() {
+                    if emitted[j] {
+                        continue;
+                    }
+                    emitted[j] = true;
+                    update.upserts.push(NodeUpsert::Insert {
+                        key: Some(after[j].id),
+                        parent: after[j].parent,
+                        local: after[j].local.clone(),
+                    });
+                }
+                continue;
+            }
+            let previous = &before_index[&key(node.id)].local;
+            if same_geometry_and_flags(previous, &node.local) {
+                if previous.z_index != node.local.z_index {
+                    update.reorders.push((node.id, node.local.z_index));
+                }
+            } else {
+                update.upserts.push(NodeUpsert::Update {
+                    id: node.id,
+                    local: node.local.clone(),
+                });
+            }
+        }
+        update.removals = before
+            .iter()
+            .filter(|node| after_index.get(&key(node.id)).is_none_or(|&i| reinsert[i]))
+            .map(|node| node.id)
+            .collect();
+        update
+    }
+}
+
+fn same_geometry_and_flags(a: &LocalNode, b: &LocalNode) -> bool {
+    a.local_bounds == b.local_bounds
+        && a.local_transform == b.local_transform
+        && a.local_clip == b.local_clip
+        && a.flags == b.flags
+        && a.hit_policy == b.hit_policy
+}
+
+impl<B: Backend<f64>> Tree<B> {
+    /// Apply a batch of changes and return the ids of inserted nodes, in upsert order.
+    ///
+    /// Removals are applied first, then upserts, then reorders. Like the individual mutation
+    /// methods, geometry and structural changes only mark the tree dirty, so the world data and
+    /// spatial index are brought up to date in a single pass by the next [`Tree::commit`] no
+    /// matter how many nodes changed.
+    pub fn apply_update(&mut self, update: TreeUpdate) -> Vec<NodeId> {
+        for id in update.removals {
+            self.remove(id);
+        }
+        let mut inserted = Vec::new();
+        let mut keys = BTreeMap::new();
+        for upsert in update.upserts {
+            match upsert {
+                NodeUpsert::Insert { key, parent, local } => {
+                    let parent = parent
+                        .map(|parent| keys.get(&(parent.0, parent.1)).copied().unwrap_or(parent));
+                    let id = self.insert(parent, local);
+                    if let Some(key) = key {
+                        keys.insert((key.0, key.1), id);
+                    }
+                    inserted.push(id);
+                }
+                NodeUpsert::Update { id, local } => {
+                    self.set_local_bounds(id, local.local_bounds);
+                    self.set_local_transform(id, local.local_transform);
+                    self.set_local_clip(id, local.local_clip);
+                    self.set_z_index(id, local.z_index);
+                    self.set_flags(id, local.flags);
+                    self.set_hit_policy(id, local.hit_policy);
+                }
+            }
+        }
+        for (id, z) in update.reorders {
+            self.set_z_index(id, z);
+        }
+        inserted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use kurbo::{Point, Rect};
+
+    use super::*;
+    use crate::tree::QueryFilter;
+
+    fn node(bounds: Rect, z_index: i32) -> LocalNode {
+        LocalNode {
+            local_bounds: bounds,
+            z_index,
+            ..LocalNode::default()
+        }
+    }
+
+    #[test]
+    fn apply_update_inserts_updates_and_removes() {
+        let mut tree = Tree::new();
+        let root = tree.insert(None, node(Rect::new(0.0, 0.0, 100.0, 100.0), 0));
+        let doomed = tree.insert(Some(root), node(Rect::new(0.0, 0.0, 10.0, 10.0), 1));
+        let _ = tree.commit();
+
+        let inserted = tree.apply_update(TreeUpdate {
+            upserts: vec![
+                NodeUpsert::Insert {
+                    key: None,
+                    parent: Some(root),
+                    local: node(Rect::new(50.0, 50.0, 60.0, 60.0), 2),
+                },
+                NodeUpsert::Update {
+                    id: root,
+                    local: node(Rect::new(0.0, 0.0, 200.0, 200.0), 0),
+                },
+            ],
+            removals: vec![doomed],
+            reorders: vec![],
+        });
+        assert_eq!(inserted.len(), 1);
+        assert!(tree.needs_commit());
+        let damage = tree.commit();
+        assert!(!damage.dirty_rects.is_empty());
+
+        assert!(!tree.is_alive(doomed));
+        assert_eq!(tree.children_of(root), &[inserted[0]]);
+        assert_eq!(
+            tree.world_bounds(root),
+            Some(Rect::new(0.0, 0.0, 200.0, 200.0))
+        );
+        let hit = tree
+            .hit_test_point(Point::new(55.0, 55.0), QueryFilter::new())
+            .unwrap();
+        assert_eq!(hit.node, inserted[0]);
+    }
+
+    #[test]
+    fn diff_splits_reorders_from_upserts() {
+        let mut tree = Tree::new();
+        let a = tree.insert(None, node(Rect::new(0.0, 0.0, 10.0, 10.0), 0));
+        let b = tree.insert(None, node(Rect::new(0.0, 0.0, 10.0, 10.0), 0));
+        let c = tree.insert(None, node(Rect::new(0.0, 0.0, 10.0, 10.0), 0));
+        let _ = tree.commit();
+
+        let before = tree.snapshot();
+        let mut after = before.clone();
+        after.retain(|node| node.id != c);
+        for node in &mut after {
+            if node.id == a {
+                node.local.z_index = 5;
+            } else if node.id == b {
+                node.local.local_bounds = Rect::new(0.0, 0.0, 20.0, 20.0);
+            }
+        }
+
+        let update = TreeUpdate::diff(&before, &after);
+        assert_eq!(update.reorders, [(a, 5)]);
+        assert_eq!(update.removals, [c]);
+        assert!(matches!(update.upserts.as_slice(), [NodeUpsert::Update { id, .. }] if *id == b));
+
+        tree.apply_update(update);
+        let _ = tree.commit();
+        assert_eq!(tree.z_index(a), Some(5));
+        assert_eq!(tree.local_bounds(b), Some(Rect::new(0.0, 0.0, 20.0, 20.0)));
+        assert!(!tree.is_alive(c));
+        assert!(TreeUpdate::diff(&tree.snapshot(), &tree.snapshot()).is_empty());
+    }
+
+    #[test]
+    fn diff_inserts_new_and_reparented_nodes() {
+        let mut tree = Tree::new();
+        let root = tree.insert(None, node(Rect::new(0.0, 0.0, 100.0, 100.0), 0));
+        let a = tree.insert(Some(root), node(Rect::new(0.0, 0.0, 10.0, 10.0), 0));
+        let b = tree.insert(Some(root), node(Rect::new(20.0, 0.0, 30.0, 10.0), 0));
+        let c = tree.insert(Some(a), node(Rect::new(0.0, 0.0, 5.0, 5.0), 0));
+        let _ = tree.commit();
+
+        let before = tree.snapshot();
+        let mut after = before.clone();
+        // Move `a` (and with it `c`) under `b`.
+        after.iter_mut().find(|n| n.id == a).unwrap().parent = Some(b);
+        // Add a new node with a child; the ids are placeholders.
+        let new_parent = NodeId::new(100, 1);
+        let new_child = NodeId::new(101, 1);
+        after.push(NodeSnapshot {
+            id: new_child,
+            parent: Some(new_parent),
+            local: node(Rect::new(60.0, 60.0, 70.0, 70.0), 0),
+        });
+        after.push(NodeSnapshot {
+            id: new_parent,
+            parent: Some(root),
+            local: node(Rect::new(50.0, 50.0, 80.0, 80.0), 0),
+        });
+
+        let update = TreeUpdate::diff(&before, &after);
+        assert_eq!(update.removals, [a, c]);
+        let keys: Vec<_> = update
+            .upserts
+            .iter()
+            .map(|upsert| match upsert {
+                NodeUpsert::Insert { key, .. } => key.unwrap(),
+                NodeUpsert::Update { .. } => panic!("unexpected update"),
+            })
+            .collect();
+        assert_eq!(keys, [a, c, new_parent, new_child]);
+
+        let inserted = tree.apply_update(update);
+        let _ = tree.commit();
+        let [new_a, new_c, parent, child] = inserted[..] else {
+            panic!("expected four inserts");
+        };
+        assert!(!tree.is_alive(a) && !tree.is_alive(c));
+        assert_eq!(tree.children_of(root), &[b, parent]);
+        assert_eq!(tree.children_of(b), &[new_a]);
+        assert_eq!(tree.children_of(new_a), &[new_c]);
+        assert_eq!(tree.children_of(parent), &[child]);
+        let hit = tree
+            .hit_test_point(Point::new(65.0, 65.0), QueryFilter::new())
+            .unwrap();
+        assert_eq!(hit.node, child);
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Z-order commands: move a node to the front, to the back, or directly above a sibling.
+
+use understory_index::Backend;
+
+use crate::tree::Tree;
+use crate::types::NodeId;
+
+impl<B: Backend<f64>> Tree<B> {
+    /// Move a node above all of its siblings.
+    ///
+    /// Siblings are the children of the node's parent, or all roots for a root node. See
+    /// [`Tree::raise_above`] for how `z_index` values are rewritten. Returns `true` if any
+    /// `z_index` changed; stale identifiers are ignored.
+    pub fn bring_to_front(&mut self, id: NodeId) -> bool {
+        self.restack(id, |order| order.len())
+    }
+
+    /// Move a node below all of its siblings.
+    ///
+    /// See [`Tree::raise_above`] for how `z_index` values are rewritten. Returns `true` if any
+    /// `z_index` changed; stale identifiers are ignored.
+    pub fn send_to_back(&mut self, id: NodeId) -> bool {
+        self.restack(id, |_| 0)
+    }
+
+    /// Move a node so that it stacks directly above its sibling `target`.
+    ///
+    /// This lowers the node if it was above `target` before. The siblings are then renumbered
+    /// with consecutive `z_index` values in their new stacking order, starting from the lowest
+    /// `z_index` among them, so repeated commands never run out of room between values. Nodes
+    /// outside the sibling group keep their `z_index`; since `z_index` is a single global order,
+    /// a sibling group spread over a wide range of values may change its order relative to
+    /// cousins that sat inside that range.
+    ///
+    /// Like [`Tree::set_z_index`], the change takes effect immediately and the bounds of every
+    /// renumbered node are reported as damage by the next [`Tree::commit`], which is how
+    /// renderers learn about the new order.
+    ///
+    /// Returns `true` if any `z_index` changed. Nothing happens if either node is stale, if the
+    /// nodes are not siblings, or if they are the same node.
+    pub fn raise_above(&mut self, id: NodeId, target: NodeId) -> bool {
+        if id == target || !self.is_alive(target) || self.parent_of(id) != self.parent_of(target) {
+            return false;
+        }
+        self.restack(id, |order| {
+            order
+                .iter()
+                .position(|sibling| *sibling == target)
+                .map_or(order.len(), |i| i + 1)
+        })
+    }
+
+    /// Move `id` among its siblings to the position chosen by `place` and renumber them densely.
+    ///
+    /// `place` receives the siblings other than `id` in stacking order (back to front) and
+    /// returns the index at which to insert `id`.
+    fn restack(&mut self, id: NodeId, place: impl FnOnce(&[NodeId]) -> usize) -> bool {
+        if !self.is_alive(id) {
+            return false;
+        }
+        let mut order = match self.parent_of(id) {
+            Some(parent) => self.children_of(parent).to_vec(),
+            None => self.roots(),
+        };
+        let Some(base) = order
+            .iter()
+            .filter_map(|&sibling| self.z_index(sibling))
+            .min()
+        else {
+            return false;
+        };
+        // Siblings share a depth, so stacking order among them is `z_index`, then age.
+        order.sort_by_key(|&sibling| (self.z_index(sibling), sibling.1, sibling.0));
+        order.retain(|&sibling| sibling != id);
+        let position = place(&order).min(order.len());
+        order.insert(position, id);
+
+        let mut changed = false;
+        for (z, sibling) in (base..).zip(order) {
+            changed |= self.z_index(sibling) != Some(z);
+            self.set_z_index(sibling, z);
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use kurbo::{Point, Rect};
+
+    use super::*;
+    use crate::tree::QueryFilter;
+    use crate::types::LocalNode;
+
+    fn stack() -> (Tree, NodeId, Vec<NodeId>) {
+        let mut tree = Tree::new();
+        let root = tree.insert(
+            None,
+            LocalNode {
+                local_bounds: Rect::new(0.0, 0.0, 100.0, 100.0),
+                ..LocalNode::default()
+            },
+        );
+        let children = (0..3)
+            .map(|_| {
+                tree.insert(
+                    Some(root),
+                    LocalNode {
+                        local_bounds: Rect::new(0.0, 0.0, 50.0, 50.0),
+                        ..LocalNode::default()
+                    },
+                )
+            })
+            .collect();
+        let _ = tree.commit();
+        (tree, root, children)
+    }
+
+    fn topmost(tree: &Tree) -> NodeId {
+        tree.hit_test_point(Point::new(10.0, 10.0), QueryFilter::new())
+            .unwrap()
+            .node
+    }
+
+    #[test]
+    fn front_and_back_renumber_densely() {
+        let (mut tree, root, nodes) = stack();
+        let [a, b, c] = nodes[..] else { unreachable!() };
+        assert_eq!(topmost(&tree), c);
+
+        assert!(tree.bring_to_front(a));
+        assert_eq!(topmost(&tree), a);
+        assert_eq!(
+            [tree.z_index(b), tree.z_index(c), tree.z_index(a)],
+            [Some(0), Some(1), Some(2)]
+        );
+        assert!(!tree.bring_to_front(a));
+
+        assert!(tree.send_to_back(a));
+        assert_eq!(tree.paint_order(QueryFilter::new()), [root, a, b, c]);
+        assert!(!tree.commit().dirty_rects.is_empty());
+    }
+
+    #[test]
+    fn raise_above_moves_up_or_down_among_siblings() {
+        let (mut tree, root, nodes) = stack();
+        let [a, b, c] = nodes[..] else { unreachable!() };
+        tree.set_z_index(b, 10);
+
+        assert!(tree.raise_above(a, b));
+        assert_eq!(tree.paint_order(QueryFilter::new()), [root, c, b, a]);
+        assert_eq!(tree.z_index(c), Some(0));
+        assert_eq!(tree.z_index(a), Some(2));
+
+        assert!(tree.raise_above(a, c));
+        assert_eq!(tree.paint_order(QueryFilter::new()), [root, c, a, b]);
+
+        // Non-siblings, self, and stale nodes are ignored.
+        assert!(!tree.raise_above(a, root));
+        assert!(!tree.raise_above(a, a));
+        tree.remove(c);
+        let _ = tree.commit();
+        assert!(!tree.raise_above(a, c));
+        assert!(!tree.bring_to_front(c));
+    }
+
+    #[test]
+    fn roots_are_siblings() {
+        let mut tree = Tree::new();
+        let roots: Vec<NodeId> = vec![
+            tree.insert(None, LocalNode::default()),
+            tree.insert(None, LocalNode::default()),
+        ];
+        let _ = tree.commit();
+        assert!(tree.send_to_back(roots[1]));
+        assert_eq!(tree.paint_order(QueryFilter::new()), [roots[1], roots[0]]);
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Cursor state helper: resolve the pointer cursor from the hover path and active gestures.
+//!
+//! ## Usage
+//!
+//! 1) On pointer move, hit test and build the root→target path (the same path fed to
+//!    [`HoverState::update_path`](crate::hover::HoverState::update_path)).
+//! 2) Call [`CursorState::update_path`] with that path and a lookup returning the cursor a node
+//!    declares, if any. The innermost node that declares a cursor wins.
+//! 3) While a gesture is active (for example dragging a handle), call
+//!    [`CursorState::set_override`] so the cursor does not change as the pointer crosses other
+//!    nodes. Clear it when the gesture ends.
+//! 4) Only apply a cursor to the host when one of these calls returns `Some(..)`. They return
+//!    `None` when the resolved cursor did not change, so hosts do not need to poll or re-set it.
+//!
+//! ## Minimal example
+//!
+//! ```
+//! use understory_event_state::cursor::{CursorIcon, CursorState};
+//!
+//! // Node 2 is a text field, node 3 is a draggable handle inside it.
+//! let declared = |node: &u32| match node {
+//!     2 => Some(CursorIcon::Text),
+//!     3 => Some(CursorIcon::Grab),
+//!     _ => None,
+//! };
+//!
+//! let mut cursor = CursorState::new(CursorIcon::Default);
+//! assert_eq!(cursor.update_path(&[1, 2], declared), Some(CursorIcon::Text));
+//! // Moving within the same node does not report a change.
+//! assert_eq!(cursor.update_path(&[1, 2], declared), None);
+//! assert_eq!(cursor.update_path(&[1, 2, 3], declared), Some(CursorIcon::Grab));
+//!
+//! // Start dragging the handle: the override wins over whatever is hovered.
+//! assert_eq!(cursor.set_override(Some(CursorIcon::Grabbing)), Some(CursorIcon::Grabbing));
+//! assert_eq!(cursor.update_path(&[1], declared), None);
+//!
+//! // Releasing falls back to the hovered cursor.
+//! assert_eq!(cursor.set_override(None), Some(CursorIcon::Default));
+//! ```
+
+/// A common set of pointer cursor shapes.
+///
+/// This mirrors the cursors most platforms and the CSS `cursor` property provide. Hosts map it
+/// to their windowing library's cursor type. [`CursorState`] is generic, so applications with
+/// their own cursor type can use that instead.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CursorIcon {
+    /// The platform default cursor, usually an arrow.
+    #[default]
+    Default,
+    /// A pointing hand, typically used for links and buttons.
+    Pointer,
+    /// A text insertion cursor (I-beam).
+    Text,
+    /// A crosshair for precise selection.
+    Crosshair,
+    /// Something can be moved.
+    Move,
+    /// Something can be grabbed.
+    Grab,
+    /// Something is being grabbed.
+    Grabbing,
+    /// The requested action is not allowed.
+    NotAllowed,
+    /// Resize horizontally (east–west).
+    EwResize,
+    /// Resize vertically (north–south).
+    NsResize,
+    /// Resize along the north-east/south-west diagonal.
+    NeswResize,
+    /// Resize along the north-west/south-east diagonal.
+    NwseResize,
+    /// Resize a column.
+    ColResize,
+    /// Resize a row.
+    RowResize,
+    /// Zoom in.
+    ZoomIn,
+    /// Zoom out.
+    ZoomOut,
+}
+
+/// Resolves the current cursor from the hovered path and an optional gesture override.
+///
+/// Resolution order:
+/// - The override set with [`CursorState::set_override`], if any.
+/// - Otherwise the cursor declared by the innermost node on the hovered path.
+/// - Otherwise the default cursor passed to [`CursorState::new`].
+///
+/// Every update returns the new cursor only when the resolved cursor changed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CursorState<C: Copy + Eq = CursorIcon> {
+    default: C,
+    hovered: Option<C>,
+    active: Option<C>,
+}
+
+impl<C: Copy + Eq + Default> Default for CursorState<C> {
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<C: Copy + Eq> CursorState<C> {
+    /// Create a cursor state that shows `default` when nothing else applies.
+    pub fn new(default: C) -> Self {
+        Self {
+            default,
+            hovered: None,
+            active: None,
+        }
+    }
+
+    /// Return the currently resolved cursor.
+    #[must_use]
+    pub fn current(&self) -> C {
+        self.active.or(self.hovered).unwrap_or(self.default)
+    }
+
+    /// Return the active gesture override, if any.
+    #[must_use]
+    pub fn override_cursor(&self) -> Option<C> {
+        self.active
+    }
+
+    /// Update the hovered root→target path.
+    ///
+    /// `declared` returns the cursor a node asks for, or `None` if it does not care. The path is
+    /// searched from the innermost node outwards, so nodes inherit their ancestors' cursors.
+    ///
+    /// Returns the new cursor if the resolved cursor changed.
+    pub fn update_path<K>(
+        &mut self,
+        path: &[K],
+        mut declared: impl FnMut(&K) -> Option<C>,
+    ) -> Option<C> {
+        let before = self.current();
+        self.hovered = path.iter().rev().find_map(&mut declared);
+        self.changed_from(before)
+    }
+
+    /// Set or clear the gesture override.
+    ///
+    /// While set, the override wins over the hovered path. Clearing it falls back to the hovered
+    /// cursor. Returns the new cursor if the resolved cursor changed.
+    pub fn set_override(&mut self, cursor: Option<C>) -> Option<C> {
+        let before = self.current();
+        self.active = cursor;
+        self.changed_from(before)
+    }
+
+    /// Forget the hovered path, for example when the pointer leaves the window.
+    ///
+    /// The override is kept. Returns the new cursor if the resolved cursor changed.
+    pub fn clear_hover(&mut self) -> Option<C> {
+        let before = self.current();
+        self.hovered = None;
+        self.changed_from(before)
+    }
+
+    fn changed_from(&self, before: C) -> Option<C> {
+        let now = self.current();
+        (now != before).then_some(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declared(node: &u32) -> Option<CursorIcon> {
+        match node {
+            10 => Some(CursorIcon::Pointer),
+            20 => Some(CursorIcon::Text),
+            _ => None,
+        }
+    }
+
+    // Innermost declaring node wins; undeclared descendants inherit.
+    #[test]
+    fn innermost_declared_cursor_wins() {
+        let mut c = CursorState::default();
+        assert_eq!(c.update_path(&[10, 20], declared), Some(CursorIcon::Text));
+        assert_eq!(c.update_path(&[10, 20, 30], declared), None);
+        assert_eq!(
+            c.update_path(&[10, 30], declared),
+            Some(CursorIcon::Pointer)
+        );
+        assert_eq!(c.update_path(&[1, 2], declared), Some(CursorIcon::Default));
+        assert_eq!(c.current(), CursorIcon::Default);
+    }
+
+    // The override masks hover changes and is the only thing reported while set.
+    #[test]
+    fn override_masks_hover() {
+        let mut c = CursorState::new(CursorIcon::Default);
+        assert_eq!(c.update_path(&[10], declared), Some(CursorIcon::Pointer));
+        assert_eq!(
+            c.set_override(Some(CursorIcon::Grabbing)),
+            Some(CursorIcon::Grabbing)
+        );
+        assert_eq!(c.update_path(&[20], declared), None);
+        assert_eq!(c.clear_hover(), None);
+        assert_eq!(c.override_cursor(), Some(CursorIcon::Grabbing));
+        assert_eq!(c.set_override(None), Some(CursorIcon::Default));
+    }
+
+    // Setting the override to the already-resolved cursor is not a change.
+    #[test]
+    fn override_equal_to_hovered_is_not_a_change() {
+        let mut c = CursorState::new(CursorIcon::Default);
+        let _ = c.update_path(&[20], declared);
+        assert_eq!(c.set_override(Some(CursorIcon::Text)), None);
+        assert_eq!(c.set_override(None), None);
+    }
+
+    // Custom cursor types work too.
+    #[test]
+    fn generic_cursor_type() {
+        let mut c: CursorState<u8> = CursorState::new(0);
+        assert_eq!(
+            c.update_path(&['a', 'b'], |k| (*k == 'a').then_some(7)),
+            Some(7)
+        );
+        assert_eq!(c.current(), 7);
+    }
+}
@@ -0,0 +1,483 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Gesture helpers: estimate pan, pinch, and rotation rates at release, and snap rotation to
+//! common angles.
+//!
+//! ## Usage
+//!
+//! 1) On pointer down (or gesture start), call [`VelocityTracker::reset`].
+//! 2) On each move, call [`VelocityTracker::add_position`] with the timestamp and pointer
+//!    position, or [`VelocityTracker::add_transform`] when a pinch/rotate recognizer also
+//!    provides a cumulative scale and rotation.
+//! 3) On pointer up, call [`VelocityTracker::summary`] with the release time to get a
+//!    [`GestureSummary`], then decide between tap, swipe, or slow-drag semantics (or feed the
+//!    velocity into a momentum animation).
+//!
+//! Velocities are estimated over the most recent [`VelocityTracker::window`] milliseconds, so
+//! a pointer that stops before it is released reports zero velocity. Times are in milliseconds
+//! from any monotonic clock, matching [`click`](crate::click).
+//!
+//! ## Minimal example
+//!
+//! ```
+//! use kurbo::{Point, Vec2};
+//! use understory_event_state::gesture::VelocityTracker;
+//!
+//! let mut tracker = VelocityTracker::new();
+//! tracker.add_position(0, Point::new(0.0, 0.0));
+//! tracker.add_position(10, Point::new(10.0, 0.0));
+//! tracker.add_position(20, Point::new(20.0, 0.0));
+//!
+//! let summary = tracker.summary(20).unwrap();
+//! assert_eq!(summary.velocity, Vec2::new(1000.0, 0.0)); // pixels per second
+//! assert_eq!(summary.total_offset, Vec2::new(20.0, 0.0));
+//! assert_eq!(summary.duration, 20);
+//! ```
+//!
+//! ## Rotation snapping
+//!
+//! [`RotationSnap`] filters the rotation of a rotate gesture or rotation handle so that it sticks
+//! to multiples of an increment (15° by default) when it comes close, and lets go once the
+//! gesture moves further away. Call [`RotationSnap::begin`] when the gesture starts, then feed
+//! each incremental rotation through [`RotationSnap::rotate_by`] and apply the returned delta
+//! instead. Pass `bypass = true` while a modifier key disables snapping.
+//!
+//! ```
+//! use understory_event_state::gesture::RotationSnap;
+//!
+//! let mut snap = RotationSnap::new();
+//! snap.begin(0.0);
+//! // 14° of raw rotation lands on 15°.
+//! let applied = snap.rotate_by(14_f64.to_radians(), false);
+//! assert!((applied - 15_f64.to_radians()).abs() < 1e-12);
+//! ```
+
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use kurbo::common::FloatFuncs as _;
+use kurbo::{Point, Vec2};
+
+/// Number of recent samples kept for velocity estimation.
+const CAPACITY: usize = 16;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Sample {
+    time: u64,
+    position: Point,
+    scale: f64,
+    rotation: f64,
+}
+
+/// Motion summary of a finished gesture, from [`VelocityTracker::summary`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GestureSummary {
+    /// Time from the first sample to the release, in milliseconds.
+    pub duration: u64,
+    /// Offset from the first sample's position to the last one.
+    pub total_offset: Vec2,
+    /// Pan velocity at release, in position units (typically pixels) per second.
+    pub velocity: Vec2,
+    /// Rate of change of the cumulative scale at release, in scale units per second.
+    ///
+    /// Zero when only positions were recorded.
+    pub scale_rate: f64,
+    /// Rate of change of the cumulative rotation at release, in radians per second.
+    ///
+    /// Zero when only positions were recorded.
+    pub rotation_rate: f64,
+}
+
+/// Tracks recent gesture samples and estimates velocities.
+///
+/// Keeps a fixed-size ring of recent samples and does not allocate. Samples that arrive faster
+/// than the ring can hold over [`VelocityTracker::window`] replace the newest one instead of
+/// pushing older samples out, so high-rate pointers are still estimated over the whole window.
+#[derive(Clone, Debug)]
+pub struct VelocityTracker {
+    /// Only samples this many milliseconds before the newest one contribute to velocities.
+    pub window: u64,
+    first: Option<Sample>,
+    samples: [Sample; CAPACITY],
+    len: usize,
+    head: usize,
+}
+
+impl Default for VelocityTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VelocityTracker {
+    /// Create an empty tracker with a 100ms estimation window.
+    pub fn new() -> Self {
+        Self {
+            window: 100,
+            first: None,
+            samples: [Sample {
+                time: 0,
+                position: Point::ZERO,
+                scale: 1.0,
+                rotation: 0.0,
+            }; CAPACITY],
+            len: 0,
+            head: 0,
+        }
+    }
+
+    /// Forget all samples, for example when a new gesture starts.
+    pub fn reset(&mut self) {
+        self.first = None;
+        self.len = 0;
+        self.head = 0;
+    }
+
+    /// Record a pointer position at `time`.
+    pub fn add_position(&mut self, time: u64, position: Point) {
+        let (scale, rotation) = self.newest().map_or((1.0, 0.0), |s| (s.scale, s.rotation));
+        self.add_transform(time, position, scale, rotation);
+    }
+
+    /// Record a position together with the gesture's cumulative scale and rotation (radians).
+    ///
+    /// Samples with non-finite values or timestamps older than the newest sample are ignored.
+    pub fn add_transform(&mut self, time: u64, position: Point, scale: f64, rotation: f64) {
+        if !(position.x.is_finite()
+            && position.y.is_finite()
+            && scale.is_finite()
+            && rotation.is_finite())
+        {
+            return;
+        }
+        if self.newest().is_some_and(|s| time < s.time) {
+            return;
+        }
+        let sample = Sample {
+            time,
+            position,
+            scale,
+            rotation,
+        };
+        if self.first.is_none() {
+            self.first = Some(sample);
+        }
+        if self.len >= 2 && self.sample(0).time - self.sample(1).time < self.min_spacing() {
+            let newest = (self.head + CAPACITY - 1) % CAPACITY;
+            self.samples[newest] = sample;
+            return;
+        }
+        self.samples[self.head] = sample;
+        self.head = (self.head + 1) % CAPACITY;
+        self.len = (self.len + 1).min(CAPACITY);
+    }
+
+    /// Summarize the gesture as of `release_time`.
+    ///
+    /// Returns `None` if no samples were recorded. If the newest sample is older than the
+    /// estimation window at `release_time`, the pointer is considered to have stopped and all
+    /// rates are zero.
+    #[must_use]
+    pub fn summary(&self, release_time: u64) -> Option<GestureSummary> {
+        let first = self.first?;
+        let newest = self.newest()?;
+        let mut summary = GestureSummary {
+            duration: release_time.saturating_sub(first.time),
+            total_offset: newest.position - first.position,
+            velocity: Vec2::ZERO,
+            scale_rate: 0.0,
+            rotation_rate: 0.0,
+        };
+        if release_time.saturating_sub(newest.time) > self.window {
+            return Some(summary);
+        }
+
+        // Oldest sample still inside the window relative to the newest one.
+        let oldest = (0..self.len)
+            .map(|age| self.sample(age))
+            .take_while(|s| newest.time - s.time <= self.window)
+            .last()?;
+        let dt_ms = newest.time - oldest.time;
+        if dt_ms == 0 {
+            return Some(summary);
+        }
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "Millisecond spans within the window are far below f64's integer precision."
+        )]
+        let dt = dt_ms as f64 / 1000.0;
+        summary.velocity = (newest.position - oldest.position) / dt;
+        summary.scale_rate = (newest.scale - oldest.scale) / dt;
+        summary.rotation_rate = (newest.rotation - oldest.rotation) / dt;
+        Some(summary)
+    }
+
+    /// Smallest time between kept samples so that the ring spans the whole window.
+    fn min_spacing(&self) -> u64 {
+        self.window.div_ceil(CAPACITY as u64 - 1)
+    }
+
+    fn newest(&self) -> Option<Sample> {
+        (self.len > 0).then(|| self.sample(0))
+    }
+
+    /// Sample `age` steps before the newest one.
+    fn sample(&self, age: usize) -> Sample {
+        self.samples[(self.head + CAPACITY - 1 - age) % CAPACITY]
+    }
+}
+
+/// Snaps the accumulated angle of a rotate gesture to multiples of an increment.
+///
+/// Snapping is sticky: the output locks onto a multiple of [`RotationSnap::increment`] once the
+/// raw angle comes within [`RotationSnap::threshold`] of it, and stays there until the raw angle
+/// moves more than [`RotationSnap::escape`] away. Angles are in radians.
+///
+/// The raw angle is tracked per gesture, so small incremental rotations accumulate instead of
+/// being swallowed by the snap.
+#[derive(Clone, Debug)]
+pub struct RotationSnap {
+    /// Spacing of snap angles, in radians. Non-positive or non-finite values disable snapping.
+    pub increment: f64,
+    /// Distance from a snap angle within which the output snaps to it, in radians.
+    pub threshold: f64,
+    /// Distance from the current snap angle beyond which the output is released, in radians.
+    ///
+    /// Values smaller than [`RotationSnap::threshold`] behave like the threshold.
+    pub escape: f64,
+    raw: f64,
+    output: f64,
+    snapped: bool,
+}
+
+impl Default for RotationSnap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RotationSnap {
+    /// Create a snapper with 15° steps, a 4° snap threshold, and an 8° escape distance.
+    pub fn new() -> Self {
+        Self {
+            increment: PI / 12.0,
+            threshold: PI / 45.0,
+            escape: PI / 22.5,
+            raw: 0.0,
+            output: 0.0,
+            snapped: false,
+        }
+    }
+
+    /// Start a new gesture at `angle`, typically the object's current rotation.
+    ///
+    /// Non-finite angles start at zero.
+    pub fn begin(&mut self, angle: f64) {
+        let angle = if angle.is_finite() { angle } else { 0.0 };
+        self.raw = angle;
+        self.output = angle;
+        self.snapped = false;
+    }
+
+    /// Accumulate an incremental rotation and return the rotation to apply instead.
+    ///
+    /// The returned delta moves the previous output angle to the new one. Non-finite deltas
+    /// are ignored and return zero.
+    pub fn rotate_by(&mut self, delta: f64, bypass: bool) -> f64 {
+        if !delta.is_finite() {
+            return 0.0;
+        }
+        let previous = self.output;
+        self.update(self.raw + delta, bypass) - previous
+    }
+
+    /// Set the raw gesture angle and return the snapped output angle.
+    ///
+    /// With `bypass` set (for example while a modifier key is held) the raw angle is returned
+    /// unchanged and any current snap is released. Non-finite angles are ignored.
+    pub fn update(&mut self, raw: f64, bypass: bool) -> f64 {
+        if !raw.is_finite() {
+            return self.output;
+        }
+        self.raw = raw;
+        let increment_valid = self.increment.is_finite() && self.increment > 0.0;
+        if bypass || !increment_valid {
+            self.snapped = false;
+            self.output = raw;
+            return raw;
+        }
+        if self.snapped && (raw - self.output).abs() <= self.escape.max(self.threshold) {
+            return self.output;
+        }
+        let nearest = (raw / self.increment).round() * self.increment;
+        self.snapped = (raw - nearest).abs() <= self.threshold;
+        self.output = if self.snapped { nearest } else { raw };
+        self.output
+    }
+
+    /// The raw angle accumulated for the current gesture.
+    #[must_use]
+    pub fn raw_angle(&self) -> f64 {
+        self.raw
+    }
+
+    /// The current output angle.
+    #[must_use]
+    pub fn angle(&self) -> f64 {
+        self.output
+    }
+
+    /// Whether the output is currently locked to a snap angle.
+    #[must_use]
+    pub fn is_snapped(&self) -> bool {
+        self.snapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_tracker_has_no_summary() {
+        let t = VelocityTracker::new();
+        assert!(t.summary(0).is_none());
+    }
+
+    // Only samples within the window contribute; an earlier slow segment is ignored.
+    #[test]
+    fn velocity_uses_recent_window() {
+        let mut t = VelocityTracker::new();
+        t.add_position(0, Point::new(0.0, 0.0));
+        t.add_position(500, Point::new(1.0, 0.0));
+        t.add_position(550, Point::new(1.0, 10.0));
+        t.add_position(600, Point::new(1.0, 20.0));
+        let s = t.summary(600).unwrap();
+        // The first sample is older than the window relative to the newest, so the estimate
+        // spans 500..600.
+        assert_eq!(s.velocity, Vec2::new(0.0, 200.0));
+        assert_eq!(s.total_offset, Vec2::new(1.0, 20.0));
+        assert_eq!(s.duration, 600);
+    }
+
+    // Holding still before release yields zero velocity.
+    #[test]
+    fn stopped_before_release() {
+        let mut t = VelocityTracker::new();
+        t.add_position(0, Point::new(0.0, 0.0));
+        t.add_position(50, Point::new(50.0, 0.0));
+        let s = t.summary(400).unwrap();
+        assert_eq!(s.velocity, Vec2::ZERO);
+        assert_eq!(s.total_offset, Vec2::new(50.0, 0.0));
+    }
+
+    #[test]
+    fn pinch_and_rotation_rates() {
+        let mut t = VelocityTracker::new();
+        t.add_transform(0, Point::ZERO, 1.0, 0.0);
+        t.add_transform(50, Point::ZERO, 1.5, 0.25);
+        let s = t.summary(50).unwrap();
+        assert_eq!(s.scale_rate, 10.0);
+        assert_eq!(s.rotation_rate, 5.0);
+
+        // Position-only samples keep the last scale and rotation.
+        t.add_position(100, Point::new(5.0, 0.0));
+        let s = t.summary(100).unwrap();
+        assert_eq!(s.scale_rate, 5.0);
+        assert_eq!(s.velocity, Vec2::new(50.0, 0.0));
+    }
+
+    // The ring keeps working after wrapping, and invalid samples are dropped.
+    #[test]
+    fn ring_wraps_and_rejects_invalid_samples() {
+        let mut t = VelocityTracker::new();
+        for i in 0..40_u32 {
+            t.add_position(u64::from(i) * 5, Point::new(f64::from(i), 0.0));
+        }
+        t.add_position(10, Point::new(1000.0, 0.0));
+        t.add_position(200, Point::new(f64::NAN, 0.0));
+        let s = t.summary(195).unwrap();
+        // Samples are thinned to cover the 100ms window, moving 1px every 5ms.
+        assert_eq!(s.velocity, Vec2::new(200.0, 0.0));
+        assert_eq!(s.total_offset, Vec2::new(39.0, 0.0));
+
+        t.reset();
+        assert!(t.summary(195).is_none());
+    }
+
+    // A 1 kHz pointer still gets a full-window estimate, so a brief stop just before
+    // release only lowers the velocity instead of zeroing it.
+    #[test]
+    fn high_rate_samples_cover_window() {
+        let mut t = VelocityTracker::new();
+        for time in 0..=300_u32 {
+            let x = f64::from(time.min(250));
+            t.add_position(u64::from(time), Point::new(x, 0.0));
+        }
+        let s = t.summary(300).unwrap();
+        assert!(
+            s.velocity.x > 400.0 && s.velocity.x < 600.0,
+            "{:?}",
+            s.velocity
+        );
+        assert_eq!(s.total_offset, Vec2::new(250.0, 0.0));
+    }
+
+    fn deg(degrees: f64) -> f64 {
+        degrees.to_radians()
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn rotation_snaps_near_increments_only() {
+        let mut snap = RotationSnap::new();
+        snap.begin(0.0);
+        assert!(close(snap.update(deg(7.0), false), deg(7.0)));
+        assert!(!snap.is_snapped());
+        assert!(close(snap.update(deg(12.0), false), deg(15.0)));
+        assert!(snap.is_snapped());
+        assert!(close(snap.update(deg(-44.0), false), deg(-45.0)));
+    }
+
+    // Once snapped, the output holds until the raw angle passes the escape distance.
+    #[test]
+    fn rotation_snap_is_sticky_until_escape() {
+        let mut snap = RotationSnap::new();
+        snap.begin(0.0);
+        snap.update(deg(14.0), false);
+        assert!(close(snap.update(deg(21.0), false), deg(15.0)));
+        assert!(close(snap.update(deg(24.0), false), deg(24.0)));
+        assert!(!snap.is_snapped());
+    }
+
+    // Small incremental deltas accumulate and eventually escape the snap.
+    #[test]
+    fn rotation_deltas_accumulate() {
+        let mut snap = RotationSnap::new();
+        snap.begin(deg(15.0));
+        let mut applied = 0.0;
+        for _ in 0..10 {
+            applied += snap.rotate_by(deg(1.0), false);
+        }
+        assert!(close(snap.raw_angle(), deg(25.0)));
+        assert!(close(applied, deg(10.0)));
+        assert!(close(snap.angle(), deg(25.0)));
+    }
+
+    #[test]
+    fn rotation_snap_bypass_and_invalid_input() {
+        let mut snap = RotationSnap::new();
+        snap.begin(0.0);
+        assert!(close(snap.update(deg(14.0), true), deg(14.0)));
+        assert!(!snap.is_snapped());
+        assert_eq!(snap.rotate_by(f64::NAN, false), 0.0);
+        assert!(close(snap.angle(), deg(14.0)));
+
+        snap.increment = 0.0;
+        assert!(close(snap.update(deg(29.0), false), deg(29.0)));
+    }
+}
@@ -14,6 +14,10 @@
 //! - [`focus`]: Manage keyboard focus state and focus transitions
 //! - [`click`]: Transform-aware click recognition with spatial/temporal tolerance
 //! - [`drag`]: Track drag operations with movement deltas and total offsets
+//! - [`gesture`]: Estimate gesture velocities at release and snap rotation to common angles
+//! - [`cursor`]: Resolve the pointer cursor from the hover path and active gestures
+//! - [`tooltip`]: Schedule tooltip show/hide with delays, warm-up, and follow-pointer
+//! - [`shortcut`]: Resolve key chords to commands per scope, with conflict detection
 //!
 //! ## Design Philosophy
 //!
@@ -144,6 +148,8 @@
 //!
 //! - `click`: Enable transform-aware click recognition (requires `kurbo` dependency)
 //! - `drag`: Enable drag state tracking (requires `kurbo` dependency)
+//! - `gesture`: Enable gesture velocity tracking and rotation snapping (requires `kurbo` dependency)
+//! - `tooltip`: Enable tooltip scheduling (requires `kurbo` dependency)
 //!
 //! This crate is `no_std` compatible (with `alloc`) for all modules.
 
@@ -154,7 +160,13 @@ extern crate alloc;
 #[cfg(feature = "click")]
 pub mod click;
 
+pub mod cursor;
 #[cfg(feature = "drag")]
 pub mod drag;
 pub mod focus;
+#[cfg(feature = "gesture")]
+pub mod gesture;
 pub mod hover;
+pub mod shortcut;
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
@@ -0,0 +1,266 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Shortcut registry: map key chords to command ids per scope, with conflict detection.
+//!
+//! ## Usage
+//!
+//! 1) Register bindings with [`ShortcutMap::register`], either globally or for a scope such as
+//!    a focused widget kind or the active tool. Registering a chord that is already bound to a
+//!    different command in the same scope fails with a [`ShortcutConflict`].
+//! 2) On each key press, build the chord from the key and modifiers and call
+//!    [`ShortcutMap::resolve`] with the currently active scopes, most specific first (for
+//!    example the focused widget's scopes from innermost to outermost, then the active tool).
+//! 3) If a command is returned, run it and stop the key event there, so the same keys do not
+//!    also reach canvas navigation or text input.
+//!
+//! The chord and scope types are generic, so this works with any keyboard event model; hosts
+//! typically use a `(Modifiers, Key)` pair as the chord.
+//!
+//! ## Minimal example
+//!
+//! ```
+//! use understory_event_state::shortcut::{ShortcutMap, ShortcutScope};
+//!
+//! #[derive(Clone, Debug, PartialEq)]
+//! enum Scope {
+//!     Canvas,
+//!     TextField,
+//! }
+//!
+//! let mut shortcuts = ShortcutMap::new();
+//! shortcuts.register(ShortcutScope::Global, "ctrl+z", "undo").unwrap();
+//! shortcuts.register(ShortcutScope::Scoped(Scope::Canvas), "=", "zoom-in").unwrap();
+//! shortcuts
+//!     .register(ShortcutScope::Scoped(Scope::TextField), "ctrl+z", "text-undo")
+//!     .unwrap();
+//!
+//! // A second binding for the same chord in the same scope is reported.
+//! let conflict = shortcuts.register(ShortcutScope::Global, "ctrl+z", "redo").unwrap_err();
+//! assert_eq!(conflict.existing, "undo");
+//!
+//! // The most specific active scope wins; global bindings are the fallback.
+//! let active = [Scope::TextField, Scope::Canvas];
+//! assert_eq!(shortcuts.resolve(&"ctrl+z", &active), Some(&"text-undo"));
+//! assert_eq!(shortcuts.resolve(&"ctrl+z", &[Scope::Canvas]), Some(&"undo"));
+//! assert_eq!(shortcuts.resolve(&"=", &[Scope::TextField]), None);
+//! ```
+
+use alloc::vec::Vec;
+
+/// Where a shortcut binding applies.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShortcutScope<S> {
+    /// Applies everywhere, below all scoped bindings.
+    Global,
+    /// Applies only while the scope is active.
+    Scoped(S),
+}
+
+/// A rejected registration: the chord is already bound to another command in that scope.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortcutConflict<C> {
+    /// The command that already owns the chord.
+    pub existing: C,
+    /// The command that was being registered.
+    pub rejected: C,
+}
+
+#[derive(Clone, Debug)]
+struct Binding<K, S, C> {
+    scope: ShortcutScope<S>,
+    chord: K,
+    command: C,
+}
+
+/// A registry of key chord → command bindings with scopes.
+///
+/// Lookups scan a small vector, which suits the tens to low hundreds of shortcuts a typical
+/// application registers, and keeps the chord and scope types free of hashing requirements.
+#[derive(Clone, Debug)]
+pub struct ShortcutMap<K, S, C> {
+    bindings: Vec<Binding<K, S, C>>,
+}
+
+impl<K, S, C> Default for ShortcutMap<K, S, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, S, C> ShortcutMap<K, S, C> {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Number of registered bindings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Whether no bindings are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Iterate over all bindings as `(scope, chord, command)`, in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = (&ShortcutScope<S>, &K, &C)> {
+        self.bindings
+            .iter()
+            .map(|b| (&b.scope, &b.chord, &b.command))
+    }
+}
+
+impl<K: PartialEq, S: PartialEq, C: PartialEq + Clone> ShortcutMap<K, S, C> {
+    /// Bind `chord` to `command` in `scope`.
+    ///
+    /// Registering the exact same binding again is a no-op. If the chord is already bound to a
+    /// different command in the same scope, the map is unchanged and the conflict is returned.
+    /// Bindings in different scopes never conflict; the more specific one shadows the other
+    /// while its scope is active.
+    pub fn register(
+        &mut self,
+        scope: ShortcutScope<S>,
+        chord: K,
+        command: C,
+    ) -> Result<(), ShortcutConflict<C>> {
+        if let Some(existing) = self.lookup(&scope, &chord) {
+            if *existing == command {
+                return Ok(());
+            }
+            return Err(ShortcutConflict {
+                existing: existing.clone(),
+                rejected: command,
+            });
+        }
+        self.bindings.push(Binding {
+            scope,
+            chord,
+            command,
+        });
+        Ok(())
+    }
+
+    /// Remove the binding for `chord` in `scope`, returning its command.
+    pub fn unregister(&mut self, scope: &ShortcutScope<S>, chord: &K) -> Option<C> {
+        let index = self
+            .bindings
+            .iter()
+            .position(|b| b.scope == *scope && b.chord == *chord)?;
+        Some(self.bindings.remove(index).command)
+    }
+
+    /// Remove every binding for `command`, for example before rebinding it.
+    pub fn unregister_command(&mut self, command: &C) {
+        self.bindings.retain(|b| b.command != *command);
+    }
+
+    /// The command bound to `chord` in exactly `scope`, ignoring other scopes.
+    #[must_use]
+    pub fn lookup(&self, scope: &ShortcutScope<S>, chord: &K) -> Option<&C> {
+        self.bindings
+            .iter()
+            .find(|b| b.scope == *scope && b.chord == *chord)
+            .map(|b| &b.command)
+    }
+
+    /// Resolve a chord against the active scopes, most specific first.
+    ///
+    /// Returns the command from the first active scope that binds the chord, falling back to
+    /// a global binding.
+    #[must_use]
+    pub fn resolve(&self, chord: &K, active_scopes: &[S]) -> Option<&C> {
+        active_scopes
+            .iter()
+            .find_map(|scope| {
+                self.bindings.iter().find(|b| {
+                    matches!(&b.scope, ShortcutScope::Scoped(s) if s == scope) && b.chord == *chord
+                })
+            })
+            .or_else(|| {
+                self.bindings
+                    .iter()
+                    .find(|b| b.scope == ShortcutScope::Global && b.chord == *chord)
+            })
+            .map(|b| &b.command)
+    }
+
+    /// The chords bound to `command` in any scope, for showing shortcuts in menus and tooltips.
+    pub fn chords_for<'a>(&'a self, command: &'a C) -> impl Iterator<Item = &'a K> + 'a {
+        self.bindings
+            .iter()
+            .filter(move |b| b.command == *command)
+            .map(|b| &b.chord)
+    }
+
+    /// Scoped bindings that shadow a global binding for the same chord with a different command.
+    ///
+    /// Shadowing is often intended (text fields taking over undo), but listing it helps catch
+    /// accidental overlaps such as a tool shortcut hiding an application command. Yields
+    /// `(scoped, global)` command pairs together with the chord.
+    pub fn shadowed(&self) -> impl Iterator<Item = (&K, &C, &C)> {
+        self.bindings.iter().filter_map(|scoped| {
+            if scoped.scope == ShortcutScope::Global {
+                return None;
+            }
+            let global = self.lookup(&ShortcutScope::Global, &scoped.chord)?;
+            (*global != scoped.command).then_some((&scoped.chord, &scoped.command, global))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Map = ShortcutMap<&'static str, u8, u32>;
+
+    #[test]
+    fn register_detects_conflicts_per_scope() {
+        let mut map = Map::new();
+        assert!(map.register(ShortcutScope::Global, "a", 1).is_ok());
+        assert!(map.register(ShortcutScope::Global, "a", 1).is_ok());
+        assert_eq!(
+            map.register(ShortcutScope::Global, "a", 2),
+            Err(ShortcutConflict {
+                existing: 1,
+                rejected: 2
+            })
+        );
+        assert!(map.register(ShortcutScope::Scoped(7), "a", 2).is_ok());
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn resolve_prefers_first_active_scope() {
+        let mut map = Map::new();
+        map.register(ShortcutScope::Global, "a", 1).unwrap();
+        map.register(ShortcutScope::Scoped(1), "a", 10).unwrap();
+        map.register(ShortcutScope::Scoped(2), "a", 20).unwrap();
+        assert_eq!(map.resolve(&"a", &[2, 1]), Some(&20));
+        assert_eq!(map.resolve(&"a", &[1, 2]), Some(&10));
+        assert_eq!(map.resolve(&"a", &[3]), Some(&1));
+        assert_eq!(map.resolve(&"b", &[1]), None);
+    }
+
+    #[test]
+    fn unregister_and_query_helpers() {
+        let mut map = Map::new();
+        map.register(ShortcutScope::Global, "a", 1).unwrap();
+        map.register(ShortcutScope::Global, "b", 1).unwrap();
+        map.register(ShortcutScope::Scoped(1), "a", 2).unwrap();
+        assert_eq!(map.chords_for(&1).copied().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(map.shadowed().collect::<Vec<_>>(), [(&"a", &2, &1)]);
+
+        assert_eq!(map.unregister(&ShortcutScope::Global, &"a"), Some(1));
+        assert_eq!(map.shadowed().count(), 0);
+        map.unregister_command(&1);
+        assert_eq!(map.resolve(&"b", &[]), None);
+        assert_eq!(map.len(), 1);
+    }
+}
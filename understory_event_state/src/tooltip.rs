@@ -0,0 +1,464 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Tooltip scheduling helper: decide when to show, move, and hide a tooltip.
+//!
+//! ## Usage
+//!
+//! 1) On pointer move, find the innermost hovered node that has a tooltip (for example by
+//!    walking the path from [`HoverState`](crate::hover::HoverState)) and call
+//!    [`TooltipState::on_hover`] with it, the pointer position, and the current time.
+//!    Pass `None` when the pointer is not over anything with a tooltip.
+//! 2) Whenever [`TooltipState::next_deadline`] returns a time, arrange to call
+//!    [`TooltipState::on_tick`] at (or after) that time so pending shows and hides fire.
+//! 3) Call [`TooltipState::dismiss`] on pointer down, key presses, or scrolling to hide the
+//!    tooltip until the pointer moves to a different node.
+//! 4) Render or remove the tooltip in response to the returned [`TooltipEvent`]s.
+//!
+//! Times are in milliseconds from any monotonic clock, matching [`click`](crate::click).
+//!
+//! ## Warm-up
+//!
+//! Like desktop toolkits, once a tooltip has been shown, moving to another node with a tooltip
+//! shows the new one immediately instead of waiting for the show delay again. This also applies
+//! for [`TooltipState::warmup`] milliseconds after a tooltip was hidden. Dismissing a tooltip
+//! with [`TooltipState::dismiss`] ends the warm-up, so the next tooltip waits for the full delay.
+//!
+//! Switching directly from one node's tooltip to another's emits only a
+//! [`TooltipEvent::Show`] for the new node; there is no [`TooltipEvent::Hide`] for the old one.
+//! Hosts that keep a tooltip surface per target should drop the previous one on `Show`.
+//!
+//! ## Minimal example
+//!
+//! ```
+//! use kurbo::Point;
+//! use understory_event_state::tooltip::{TooltipEvent, TooltipState};
+//!
+//! let mut tooltip: TooltipState<u32> = TooltipState::new();
+//! let p = Point::new(10.0, 10.0);
+//!
+//! // Hovering starts the show delay.
+//! assert_eq!(tooltip.on_hover(Some(7), p, 0), None);
+//! assert_eq!(tooltip.next_deadline(), Some(tooltip.show_delay));
+//! assert_eq!(
+//!     tooltip.on_tick(tooltip.show_delay),
+//!     Some(TooltipEvent::Show { target: 7, position: p })
+//! );
+//!
+//! // Moving to another node while warm shows its tooltip right away.
+//! assert_eq!(
+//!     tooltip.on_hover(Some(8), p, 600),
+//!     Some(TooltipEvent::Show { target: 8, position: p })
+//! );
+//!
+//! // Leaving starts the hide delay.
+//! assert_eq!(tooltip.on_hover(None, p, 700), None);
+//! assert_eq!(tooltip.on_tick(700 + tooltip.hide_delay), Some(TooltipEvent::Hide(8)));
+//! ```
+
+use kurbo::Point;
+
+/// A tooltip transition for the host to render.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TooltipEvent<K> {
+    /// Show the tooltip for `target` at `position`.
+    ///
+    /// If another node's tooltip is shown, this replaces it: no [`TooltipEvent::Hide`] is
+    /// emitted for the previous target.
+    Show {
+        /// The node whose tooltip should be shown.
+        target: K,
+        /// The pointer position the tooltip should be placed relative to.
+        position: Point,
+    },
+    /// Move the visible tooltip for `target` to follow the pointer.
+    ///
+    /// Only emitted when [`TooltipState::follow_pointer`] is enabled.
+    Move {
+        /// The node whose tooltip is shown.
+        target: K,
+        /// The new pointer position.
+        position: Point,
+    },
+    /// Hide the tooltip for the given node.
+    Hide(K),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Phase<K> {
+    Idle,
+    Pending {
+        target: K,
+        position: Point,
+        due: u64,
+    },
+    Shown {
+        target: K,
+        position: Point,
+    },
+    Hiding {
+        target: K,
+        position: Point,
+        due: u64,
+    },
+}
+
+/// Tooltip scheduling state machine.
+///
+/// Tracks a single tooltip. Feed it hover changes and clock ticks; it returns at most one
+/// [`TooltipEvent`] per call.
+#[derive(Clone, Debug)]
+pub struct TooltipState<K> {
+    /// Time the pointer must rest on a node before its tooltip is shown (milliseconds).
+    pub show_delay: u64,
+    /// Time a shown tooltip stays up after the pointer leaves its node (milliseconds).
+    ///
+    /// Returning to the node within this time keeps the tooltip up.
+    pub hide_delay: u64,
+    /// How long after a tooltip hides that a new one is shown without delay (milliseconds).
+    pub warmup: u64,
+    /// Emit [`TooltipEvent::Move`] as the pointer moves over a node whose tooltip is shown.
+    pub follow_pointer: bool,
+    phase: Phase<K>,
+    last_hidden: Option<u64>,
+    suppressed: Option<K>,
+}
+
+impl<K: Copy + Eq> Default for TooltipState<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Copy + Eq> TooltipState<K> {
+    /// Create a tooltip state with default timings.
+    ///
+    /// Defaults: 500ms show delay, 100ms hide delay, 500ms warm-up, and a tooltip that stays
+    /// where it was shown.
+    pub fn new() -> Self {
+        Self {
+            show_delay: 500,
+            hide_delay: 100,
+            warmup: 500,
+            follow_pointer: false,
+            phase: Phase::Idle,
+            last_hidden: None,
+            suppressed: None,
+        }
+    }
+
+    /// Return the node whose tooltip is currently shown and where, if any.
+    ///
+    /// A tooltip waiting out its hide delay still counts as shown.
+    #[must_use]
+    pub fn visible(&self) -> Option<(K, Point)> {
+        match self.phase {
+            Phase::Shown { target, position }
+            | Phase::Hiding {
+                target, position, ..
+            } => Some((target, position)),
+            Phase::Idle | Phase::Pending { .. } => None,
+        }
+    }
+
+    /// Return the time at which [`TooltipState::on_tick`] should next be called, if any.
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<u64> {
+        match self.phase {
+            Phase::Pending { due, .. } | Phase::Hiding { due, .. } => Some(due),
+            Phase::Idle | Phase::Shown { .. } => None,
+        }
+    }
+
+    /// Update the hovered node that has a tooltip.
+    ///
+    /// `target` is the innermost hovered node with a tooltip, or `None`. `position` is the
+    /// pointer position and `now` the current time in milliseconds.
+    pub fn on_hover(
+        &mut self,
+        target: Option<K>,
+        position: Point,
+        now: u64,
+    ) -> Option<TooltipEvent<K>> {
+        if self.suppressed.is_some() && self.suppressed != target {
+            self.suppressed = None;
+        }
+        let target = target.filter(|t| self.suppressed != Some(*t));
+
+        match (self.phase, target) {
+            (Phase::Idle, None) => None,
+            (Phase::Idle, Some(t)) => {
+                if self.is_warm(now) {
+                    self.show(t, position)
+                } else {
+                    self.phase = Phase::Pending {
+                        target: t,
+                        position,
+                        due: now.saturating_add(self.show_delay),
+                    };
+                    None
+                }
+            }
+            (
+                Phase::Pending {
+                    target: current,
+                    due,
+                    ..
+                },
+                Some(t),
+            ) if current == t => {
+                self.phase = Phase::Pending {
+                    target: t,
+                    position,
+                    due,
+                };
+                None
+            }
+            (Phase::Pending { .. }, Some(t)) => {
+                self.phase = Phase::Pending {
+                    target: t,
+                    position,
+                    due: now.saturating_add(self.show_delay),
+                };
+                None
+            }
+            (Phase::Pending { .. }, None) => {
+                self.phase = Phase::Idle;
+                None
+            }
+            (
+                Phase::Shown {
+                    target: current,
+                    position: shown_at,
+                }
+                | Phase::Hiding {
+                    target: current,
+                    position: shown_at,
+                    ..
+                },
+                Some(t),
+            ) if current == t => {
+                if self.follow_pointer && shown_at != position {
+                    self.phase = Phase::Shown {
+                        target: t,
+                        position,
+                    };
+                    Some(TooltipEvent::Move {
+                        target: t,
+                        position,
+                    })
+                } else {
+                    self.phase = Phase::Shown {
+                        target: t,
+                        position: shown_at,
+                    };
+                    None
+                }
+            }
+            (Phase::Shown { .. } | Phase::Hiding { .. }, Some(t)) => self.show(t, position),
+            (Phase::Shown { target, position }, None) => {
+                self.phase = Phase::Hiding {
+                    target,
+                    position,
+                    due: now.saturating_add(self.hide_delay),
+                };
+                None
+            }
+            (Phase::Hiding { .. }, None) => None,
+        }
+    }
+
+    /// Advance timers, firing a pending show or hide whose deadline has passed.
+    pub fn on_tick(&mut self, now: u64) -> Option<TooltipEvent<K>> {
+        match self.phase {
+            Phase::Pending {
+                target,
+                position,
+                due,
+            } if now >= due => self.show(target, position),
+            Phase::Hiding { target, due, .. } if now >= due => self.hide(target, now),
+            _ => None,
+        }
+    }
+
+    /// Hide any tooltip immediately and cancel a pending one.
+    ///
+    /// Use this for pointer presses, key presses, and scrolling. The dismissed node does not
+    /// show its tooltip again until the pointer has moved to a different node, and unlike a
+    /// delayed hide, dismissing does not start the warm-up period.
+    pub fn dismiss(&mut self) -> Option<TooltipEvent<K>> {
+        match self.phase {
+            Phase::Idle => None,
+            Phase::Pending { target, .. } => {
+                self.suppressed = Some(target);
+                self.phase = Phase::Idle;
+                None
+            }
+            Phase::Shown { target, .. } | Phase::Hiding { target, .. } => {
+                self.suppressed = Some(target);
+                self.phase = Phase::Idle;
+                self.last_hidden = None;
+                Some(TooltipEvent::Hide(target))
+            }
+        }
+    }
+
+    fn is_warm(&self, now: u64) -> bool {
+        self.last_hidden
+            .is_some_and(|hidden| now.saturating_sub(hidden) <= self.warmup)
+    }
+
+    fn show(&mut self, target: K, position: Point) -> Option<TooltipEvent<K>> {
+        self.phase = Phase::Shown { target, position };
+        Some(TooltipEvent::Show { target, position })
+    }
+
+    fn hide(&mut self, target: K, now: u64) -> Option<TooltipEvent<K>> {
+        self.phase = Phase::Idle;
+        self.last_hidden = Some(now);
+        Some(TooltipEvent::Hide(target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P: Point = Point::new(1.0, 2.0);
+    const Q: Point = Point::new(5.0, 6.0);
+
+    fn shown(state: &mut TooltipState<u32>, target: u32, now: u64) -> u64 {
+        assert_eq!(state.on_hover(Some(target), P, now), None);
+        let due = state.next_deadline().unwrap();
+        assert_eq!(
+            state.on_tick(due),
+            Some(TooltipEvent::Show {
+                target,
+                position: P
+            })
+        );
+        due
+    }
+
+    // Nothing is shown before the show delay elapses, and leaving early cancels it.
+    #[test]
+    fn show_delay_and_cancel() {
+        let mut t = TooltipState::new();
+        assert_eq!(t.on_hover(Some(1), P, 0), None);
+        assert_eq!(t.on_tick(499), None);
+        assert_eq!(t.on_hover(None, P, 400), None);
+        assert_eq!(t.next_deadline(), None);
+        assert_eq!(t.on_tick(1000), None);
+        assert_eq!(t.visible(), None);
+    }
+
+    // Moving within the pending node keeps the deadline but reports the latest position.
+    #[test]
+    fn pending_tracks_position_and_restarts_on_new_target() {
+        let mut t = TooltipState::new();
+        let _ = t.on_hover(Some(1), P, 0);
+        let _ = t.on_hover(Some(1), Q, 200);
+        assert_eq!(t.next_deadline(), Some(500));
+        let _ = t.on_hover(Some(2), Q, 300);
+        assert_eq!(t.next_deadline(), Some(800));
+        assert_eq!(
+            t.on_tick(800),
+            Some(TooltipEvent::Show {
+                target: 2,
+                position: Q
+            })
+        );
+    }
+
+    // Returning within the hide delay keeps the tooltip up.
+    #[test]
+    fn hide_delay_can_be_cancelled() {
+        let mut t = TooltipState::new();
+        let at = shown(&mut t, 1, 0);
+        assert_eq!(t.on_hover(None, P, at + 10), None);
+        assert_eq!(t.visible(), Some((1, P)));
+        assert_eq!(t.on_hover(Some(1), P, at + 50), None);
+        assert_eq!(t.next_deadline(), None);
+        assert_eq!(t.on_tick(at + 1000), None);
+        assert_eq!(t.visible(), Some((1, P)));
+    }
+
+    // After hiding, a new hover within the warm-up window shows immediately; later it waits.
+    #[test]
+    fn warmup_window() {
+        let mut t = TooltipState::new();
+        let at = shown(&mut t, 1, 0);
+        let _ = t.on_hover(None, P, at);
+        let hidden_at = at + t.hide_delay;
+        assert_eq!(t.on_tick(hidden_at), Some(TooltipEvent::Hide(1)));
+        assert_eq!(
+            t.on_hover(Some(2), P, hidden_at + 100),
+            Some(TooltipEvent::Show {
+                target: 2,
+                position: P
+            })
+        );
+        let _ = t.on_hover(None, P, hidden_at + 200);
+        let hidden_again = hidden_at + 200 + t.hide_delay;
+        assert_eq!(t.on_tick(hidden_again), Some(TooltipEvent::Hide(2)));
+        assert_eq!(t.on_hover(Some(3), P, hidden_again + t.warmup + 1), None);
+    }
+
+    // Follow-pointer emits moves only when enabled.
+    #[test]
+    fn follow_pointer_moves() {
+        let mut t = TooltipState::new();
+        let at = shown(&mut t, 1, 0);
+        assert_eq!(t.on_hover(Some(1), Q, at + 1), None);
+        assert_eq!(t.visible(), Some((1, P)));
+        t.follow_pointer = true;
+        assert_eq!(
+            t.on_hover(Some(1), Q, at + 2),
+            Some(TooltipEvent::Move {
+                target: 1,
+                position: Q
+            })
+        );
+        assert_eq!(t.on_hover(Some(1), Q, at + 3), None);
+    }
+
+    // Dismissing hides right away and suppresses the node until the pointer moves elsewhere.
+    #[test]
+    fn dismiss_suppresses_until_target_changes() {
+        let mut t = TooltipState::new();
+        let at = shown(&mut t, 1, 0);
+        assert_eq!(t.dismiss(), Some(TooltipEvent::Hide(1)));
+        assert_eq!(t.on_hover(Some(1), P, at + 2), None);
+        assert_eq!(t.next_deadline(), None);
+        assert_eq!(t.on_hover(None, P, at + 3), None);
+        assert_eq!(t.on_hover(Some(1), P, at + 4000), None);
+        assert_eq!(t.next_deadline(), Some(at + 4000 + t.show_delay));
+        assert_eq!(t.dismiss(), None);
+        assert_eq!(t.next_deadline(), None);
+    }
+
+    // A dismissed tooltip does not warm up the next one.
+    #[test]
+    fn dismiss_does_not_warm_up() {
+        let mut t = TooltipState::new();
+        let at = shown(&mut t, 1, 0);
+        assert_eq!(t.dismiss(), Some(TooltipEvent::Hide(1)));
+        assert_eq!(t.on_hover(Some(2), P, at + 2), None);
+        assert_eq!(t.next_deadline(), Some(at + 2 + t.show_delay));
+    }
+
+    // Switching targets while shown replaces the tooltip with a single show.
+    #[test]
+    fn switching_targets_replaces_tooltip() {
+        let mut t = TooltipState::new();
+        let at = shown(&mut t, 1, 0);
+        assert_eq!(
+            t.on_hover(Some(2), Q, at + 1),
+            Some(TooltipEvent::Show {
+                target: 2,
+                position: Q
+            })
+        );
+        assert_eq!(t.visible(), Some((2, Q)));
+    }
+}
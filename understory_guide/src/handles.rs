@@ -0,0 +1,434 @@
+// Copyright 2026 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Geometry and drag mapping for selection transform handles.
+
+use kurbo::{Affine, Point, Rect, Vec2};
+
+/// Position of a scale handle on the selection bounds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HandlePosition {
+    /// Top-left corner.
+    TopLeft,
+    /// Middle of the top edge.
+    Top,
+    /// Top-right corner.
+    TopRight,
+    /// Middle of the right edge.
+    Right,
+    /// Bottom-right corner.
+    BottomRight,
+    /// Middle of the bottom edge.
+    Bottom,
+    /// Bottom-left corner.
+    BottomLeft,
+    /// Middle of the left edge.
+    Left,
+}
+
+impl HandlePosition {
+    /// All handle positions, corners first so they win hit tests over edges.
+    pub const ALL: [Self; 8] = [
+        Self::TopLeft,
+        Self::TopRight,
+        Self::BottomRight,
+        Self::BottomLeft,
+        Self::Top,
+        Self::Right,
+        Self::Bottom,
+        Self::Left,
+    ];
+
+    /// Returns `true` for the four corner handles.
+    #[must_use]
+    pub fn is_corner(self) -> bool {
+        matches!(
+            self,
+            Self::TopLeft | Self::TopRight | Self::BottomRight | Self::BottomLeft
+        )
+    }
+
+    /// Returns the handle on the opposite side of the bounds.
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::TopLeft => Self::BottomRight,
+            Self::Top => Self::Bottom,
+            Self::TopRight => Self::BottomLeft,
+            Self::Right => Self::Left,
+            Self::BottomRight => Self::TopLeft,
+            Self::Bottom => Self::Top,
+            Self::BottomLeft => Self::TopRight,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Which axes the handle scales along, as `(x, y)`.
+    fn axes(self) -> (bool, bool) {
+        match self {
+            Self::Top | Self::Bottom => (false, true),
+            Self::Left | Self::Right => (true, false),
+            _ => (true, true),
+        }
+    }
+
+    /// Normalized location on the bounds, from `(0, 0)` top-left to `(1, 1)` bottom-right.
+    fn unit(self) -> (f64, f64) {
+        match self {
+            Self::TopLeft => (0.0, 0.0),
+            Self::Top => (0.5, 0.0),
+            Self::TopRight => (1.0, 0.0),
+            Self::Right => (1.0, 0.5),
+            Self::BottomRight => (1.0, 1.0),
+            Self::Bottom => (0.5, 1.0),
+            Self::BottomLeft => (0.0, 1.0),
+            Self::Left => (0.0, 0.5),
+        }
+    }
+}
+
+/// A manipulation target produced by [`TransformHandles::hit_test`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransformHandle {
+    /// The body of the selection, for moving it.
+    Move,
+    /// The rotation handle above the top edge.
+    Rotate,
+    /// A corner or edge scale handle.
+    Scale(HandlePosition),
+}
+
+/// Sizes of transform handles, in view pixels.
+///
+/// Handles keep a constant on-screen size, so their world-space geometry depends on zoom.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HandleStyle {
+    /// Side length of the square scale handles.
+    pub handle_size: f64,
+    /// Extra distance around each handle that still hits it.
+    pub hit_slop: f64,
+    /// Distance from the top edge to the rotation handle.
+    pub rotate_offset: f64,
+}
+
+impl Default for HandleStyle {
+    fn default() -> Self {
+        Self {
+            handle_size: 8.0,
+            hit_slop: 4.0,
+            rotate_offset: 24.0,
+        }
+    }
+}
+
+/// Options that modify how a scale drag is mapped to a transform.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct HandleDragOptions {
+    /// Keep the aspect ratio when dragging a corner handle, typically while shift is held.
+    pub keep_aspect: bool,
+    /// Scale about the center instead of the opposite handle, typically while alt is held.
+    pub from_center: bool,
+}
+
+/// Transform handles around a selection's bounding box.
+///
+/// Given world-space selection bounds and the current view zoom, this computes the geometry of
+/// the standard manipulation handles (corner and edge scale handles, a rotation handle, and the
+/// move area), hit tests them, and maps handle drags to world-space [`Affine`] transforms that
+/// callers apply to the selected objects.
+///
+/// ```rust
+/// use kurbo::{Point, Rect};
+/// use understory_guide::{
+///     HandleDragOptions, HandlePosition, HandleStyle, TransformHandle, TransformHandles,
+/// };
+///
+/// let handles = TransformHandles::new(
+///     Rect::new(0.0, 0.0, 100.0, 50.0),
+///     1.0,
+///     HandleStyle::default(),
+/// );
+/// let grab = Point::new(100.0, 50.0);
+/// let handle = handles.hit_test(grab).unwrap();
+/// assert_eq!(handle, TransformHandle::Scale(HandlePosition::BottomRight));
+///
+/// // Dragging the bottom-right corner out by (100, 50) doubles the size about the top-left.
+/// let transform = handles.drag(
+///     handle,
+///     grab,
+///     Point::new(200.0, 100.0),
+///     HandleDragOptions::default(),
+/// );
+/// assert_eq!(
+///     transform.transform_rect_bbox(handles.bounds()),
+///     Rect::new(0.0, 0.0, 200.0, 100.0)
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TransformHandles {
+    bounds: Rect,
+    px: f64,
+    style: HandleStyle,
+}
+
+impl TransformHandles {
+    /// Creates handles for world-space `bounds` viewed at `zoom`.
+    ///
+    /// Invalid zoom values are treated as `1.0`.
+    #[must_use]
+    pub fn new(bounds: Rect, zoom: f64, style: HandleStyle) -> Self {
+        let zoom = if zoom.is_finite() && zoom > 0.0 {
+            zoom
+        } else {
+            1.0
+        };
+        Self {
+            bounds: bounds.abs(),
+            px: 1.0 / zoom,
+            style,
+        }
+    }
+
+    /// Returns the selection bounds in world coordinates.
+    #[must_use]
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Returns the point on the bounds where a scale handle sits.
+    #[must_use]
+    pub fn anchor(&self, position: HandlePosition) -> Point {
+        let (u, v) = position.unit();
+        let b = self.bounds;
+        Point::new(b.x0 + b.width() * u, b.y0 + b.height() * v)
+    }
+
+    /// Returns the world-space square of a scale handle, for drawing.
+    #[must_use]
+    pub fn handle_rect(&self, position: HandlePosition) -> Rect {
+        let half = self.style.handle_size * 0.5 * self.px;
+        Rect::from_center_size(self.anchor(position), (half * 2.0, half * 2.0))
+    }
+
+    /// Returns the world-space center of the rotation handle.
+    #[must_use]
+    pub fn rotate_handle(&self) -> Point {
+        self.anchor(HandlePosition::Top) - Vec2::new(0.0, self.style.rotate_offset * self.px)
+    }
+
+    /// Hit tests a world-space point against the handles.
+    ///
+    /// The rotation handle wins over scale handles, corners win over edges, and the move area
+    /// (the bounds themselves) is last.
+    #[must_use]
+    pub fn hit_test(&self, point: Point) -> Option<TransformHandle> {
+        let slop = self.style.hit_slop * self.px;
+        let rotate_radius = self.style.handle_size * 0.5 * self.px + slop;
+        if point.distance(self.rotate_handle()) <= rotate_radius {
+            return Some(TransformHandle::Rotate);
+        }
+        HandlePosition::ALL
+            .into_iter()
+            .find(|position| {
+                self.handle_rect(*position)
+                    .inflate(slop, slop)
+                    .contains(point)
+            })
+            .map(TransformHandle::Scale)
+            .or_else(|| self.bounds.contains(point).then_some(TransformHandle::Move))
+    }
+
+    /// Maps a drag of `handle` from `start` to `current` (both in world coordinates) to a
+    /// world-space transform relative to the state at `start`.
+    ///
+    /// Scale drags that would divide by a zero-sized bounds axis leave that axis unscaled.
+    #[must_use]
+    pub fn drag(
+        &self,
+        handle: TransformHandle,
+        start: Point,
+        current: Point,
+        options: HandleDragOptions,
+    ) -> Affine {
+        match handle {
+            TransformHandle::Move => Affine::translate(current - start),
+            TransformHandle::Rotate => {
+                Affine::rotate_about(self.rotation_angle(start, current), self.bounds.center())
+            }
+            TransformHandle::Scale(position) => {
+                let pivot = if options.from_center {
+                    self.bounds.center()
+                } else {
+                    self.anchor(position.opposite())
+                };
+                let from = self.anchor(position) - pivot;
+                let to = from + (current - start);
+                let (scale_x, scale_y) = position.axes();
+                let ratio = |enabled: bool, to: f64, from: f64| {
+                    if enabled && from != 0.0 {
+                        to / from
+                    } else {
+                        1.0
+                    }
+                };
+                let mut sx = ratio(scale_x, to.x, from.x);
+                let mut sy = ratio(scale_y, to.y, from.y);
+                if options.keep_aspect && position.is_corner() {
+                    let s = sx.abs().max(sy.abs());
+                    sx = s.copysign(sx);
+                    sy = s.copysign(sy);
+                }
+                Affine::translate(pivot.to_vec2())
+                    * Affine::scale_non_uniform(sx, sy)
+                    * Affine::translate(-pivot.to_vec2())
+            }
+        }
+    }
+
+    /// Returns the rotation, in radians, of a rotate drag from `start` to `current` about the
+    /// bounds center.
+    ///
+    /// The angle is the shorter way around, between `-π` and `π`. To follow a drag past a half
+    /// turn, pass the previous pointer position as `start` on each move and accumulate the
+    /// results, for example through rotation snapping's incremental input.
+    #[must_use]
+    pub fn rotation_angle(&self, start: Point, current: Point) -> f64 {
+        let center = self.bounds.center();
+        let (from, to) = (start - center, current - center);
+        Vec2::new(from.dot(to), from.cross(to)).atan2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handles(zoom: f64) -> TransformHandles {
+        TransformHandles::new(
+            Rect::new(0.0, 0.0, 100.0, 50.0),
+            zoom,
+            HandleStyle::default(),
+        )
+    }
+
+    #[test]
+    fn handle_geometry_keeps_screen_size() {
+        let h = handles(2.0);
+        assert_eq!(h.anchor(HandlePosition::Right), Point::new(100.0, 25.0));
+        assert_eq!(
+            h.handle_rect(HandlePosition::TopLeft),
+            Rect::new(-2.0, -2.0, 2.0, 2.0)
+        );
+        assert_eq!(h.rotate_handle(), Point::new(50.0, -12.0));
+    }
+
+    #[test]
+    fn hit_test_priorities() {
+        let h = handles(1.0);
+        assert_eq!(
+            h.hit_test(Point::new(50.0, -24.0)),
+            Some(TransformHandle::Rotate)
+        );
+        assert_eq!(
+            h.hit_test(Point::new(-5.0, 3.0)),
+            Some(TransformHandle::Scale(HandlePosition::TopLeft))
+        );
+        assert_eq!(
+            h.hit_test(Point::new(50.0, 52.0)),
+            Some(TransformHandle::Scale(HandlePosition::Bottom))
+        );
+        assert_eq!(
+            h.hit_test(Point::new(30.0, 20.0)),
+            Some(TransformHandle::Move)
+        );
+        assert_eq!(h.hit_test(Point::new(200.0, 20.0)), None);
+    }
+
+    #[test]
+    fn edge_drag_scales_one_axis_about_opposite_edge() {
+        let h = handles(1.0);
+        let t = h.drag(
+            TransformHandle::Scale(HandlePosition::Left),
+            Point::new(0.0, 25.0),
+            Point::new(-100.0, 40.0),
+            HandleDragOptions::default(),
+        );
+        assert_eq!(
+            t.transform_rect_bbox(h.bounds()),
+            Rect::new(-100.0, 0.0, 100.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn corner_drag_options() {
+        let h = handles(1.0);
+        let handle = TransformHandle::Scale(HandlePosition::BottomRight);
+        let start = Point::new(100.0, 50.0);
+        let aspect = HandleDragOptions {
+            keep_aspect: true,
+            from_center: false,
+        };
+        let t = h.drag(handle, start, Point::new(300.0, 60.0), aspect);
+        assert_eq!(
+            t.transform_rect_bbox(h.bounds()),
+            Rect::new(0.0, 0.0, 300.0, 150.0)
+        );
+
+        let centered = HandleDragOptions {
+            keep_aspect: false,
+            from_center: true,
+        };
+        let t = h.drag(handle, start, Point::new(150.0, 50.0), centered);
+        assert_eq!(
+            t.transform_rect_bbox(h.bounds()),
+            Rect::new(-50.0, 0.0, 150.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn move_and_rotate_drags() {
+        let h = handles(1.0);
+        let t = h.drag(
+            TransformHandle::Move,
+            Point::new(10.0, 10.0),
+            Point::new(15.0, 20.0),
+            HandleDragOptions::default(),
+        );
+        assert_eq!(t * Point::ZERO, Point::new(5.0, 10.0));
+
+        // Dragging from above the center to its right rotates a quarter turn.
+        let start = Point::new(50.0, -10.0);
+        let current = Point::new(100.0, 25.0);
+        let angle = h.rotation_angle(start, current);
+        assert!((angle - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        let t = h.drag(
+            TransformHandle::Rotate,
+            start,
+            current,
+            HandleDragOptions::default(),
+        );
+        let center = h.bounds().center();
+        assert!((t * center).distance(center) < 1e-12);
+    }
+
+    // Crossing the pointer angle seam to the left of the center does not jump by a full turn.
+    #[test]
+    fn rotation_angle_is_continuous_across_seam() {
+        let h = handles(1.0);
+        let center = h.bounds().center();
+        let start = center + Vec2::new(-10.0, -1.0);
+        let current = center + Vec2::new(-10.0, 1.0);
+        let angle = h.rotation_angle(start, current);
+        assert!((angle + 2.0 * 0.1_f64.atan()).abs() < 1e-12, "{angle}");
+
+        // Accumulating per-move angles follows the drag past a half turn.
+        let mut total = 0.0;
+        let mut previous = center + Vec2::new(10.0, 0.0);
+        for step in 1..=12 {
+            let next = center + Vec2::from_angle(f64::from(step) * 0.3) * 10.0;
+            total += h.rotation_angle(previous, next);
+            previous = next;
+        }
+        assert!((total - 3.6).abs() < 1e-12, "{total}");
+    }
+}
@@ -14,6 +14,12 @@
 //! - line-guide pose and projection math
 //! - semantic hit targets for guide body and endpoint handles
 //! - lifting [`understory_axis::AxisRuler1D`] marks into 2D geometry
+//! - snapping points and rectangles to grids, guides, and candidate geometry
+//!   with a zoom-independent snap distance ([`SnapEngine`])
+//! - selection transform handle geometry, hit testing, and mapping handle drags
+//!   to transforms ([`TransformHandles`])
+//! - rulers along the edges of an [`understory_view2d::Viewport2D`] ([`ViewportRuler`]) and
+//!   draggable guide lines that feed the snap engine ([`RulerGuides`])
 //!
 //! It does not own:
 //! - rendering
@@ -27,6 +33,19 @@
 
 extern crate alloc;
 
+mod handles;
+mod rulers;
+mod snap;
+
+pub use handles::{
+    HandleDragOptions, HandlePosition, HandleStyle, TransformHandle, TransformHandles,
+};
+pub use rulers::{
+    GuideOrientation, RulerGuide, RulerGuides, ViewportRuler, ViewportRulerMark,
+    ViewportRulerOptions,
+};
+pub use snap::{AxisSnapHit, SnapEngine, SnapGrid, SnapHit, SnapKind};
+
 use alloc::vec::Vec;
 use core::f64::consts::PI;
 
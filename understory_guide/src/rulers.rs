@@ -0,0 +1,392 @@
+// Copyright 2026 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Viewport rulers and draggable guide lines for design-tool chrome.
+
+use alloc::vec::Vec;
+
+use kurbo::{Line, Point, Rect};
+use understory_axis::{
+    AxisMapping1D, AxisRuler1D, AxisRulerOptions, AxisScale1D, AxisScaleOptions, AxisTickKind,
+};
+use understory_view2d::Viewport2D;
+
+use crate::SnapEngine;
+
+/// The direction a ruler or guide line runs in.
+///
+/// A horizontal ruler runs along the top of the view and measures x; dragging out of it creates
+/// a horizontal guide, which marks a y position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GuideOrientation {
+    /// Runs left to right.
+    Horizontal,
+    /// Runs top to bottom.
+    Vertical,
+}
+
+/// Options for [`ViewportRuler::from_viewport`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ViewportRulerOptions {
+    /// Width of the ruler strip across its direction, in view units.
+    pub thickness: f64,
+    /// Tick density and labeling policy.
+    pub scale: AxisScaleOptions,
+    /// Tick mark lengths, in view units.
+    pub marks: AxisRulerOptions,
+    /// Distance from a major tick to its label anchor along the ruler, in view units.
+    pub label_offset: f64,
+}
+
+impl Default for ViewportRulerOptions {
+    fn default() -> Self {
+        Self {
+            thickness: 20.0,
+            scale: AxisScaleOptions::default(),
+            marks: AxisRulerOptions {
+                major_mark_extent: 20.0,
+                medium_mark_extent: 8.0,
+                minor_mark_extent: 4.0,
+            },
+            label_offset: 3.0,
+        }
+    }
+}
+
+/// A ruler tick in view coordinates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ViewportRulerMark {
+    /// World coordinate of the tick.
+    pub value: f64,
+    /// Semantic tick kind.
+    pub kind: AxisTickKind,
+    /// Whether a higher layer should consider labeling this mark.
+    pub labeled: bool,
+    /// The tick line, from the ruler's inner edge towards its outer edge.
+    pub line: Line,
+    /// Suggested top-left anchor for the label, just past the tick along the ruler.
+    pub label_anchor: Point,
+}
+
+/// A ruler strip along the top or left edge of a [`Viewport2D`].
+///
+/// Ticks are produced by the same [`AxisScale1D`] policy used for other axes, mapped through the
+/// viewport's visible world rectangle, so they line up with content at any pan and zoom. Rebuild
+/// the ruler whenever the viewport changes.
+///
+/// The horizontal and vertical rulers overlap in the top-left corner; hosts usually paint a
+/// corner box over that square.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewportRuler {
+    orientation: GuideOrientation,
+    band: Rect,
+    marks: Vec<ViewportRulerMark>,
+}
+
+impl ViewportRuler {
+    /// Builds the ruler for the viewport's current pan and zoom.
+    #[must_use]
+    pub fn from_viewport(
+        view: &Viewport2D,
+        orientation: GuideOrientation,
+        options: ViewportRulerOptions,
+    ) -> Self {
+        let view_rect = view.view_rect();
+        let world = view.visible_world_rect();
+        let thickness = options.thickness.max(0.0);
+        let (band, mapping) = match orientation {
+            GuideOrientation::Horizontal => (
+                Rect::new(
+                    view_rect.x0,
+                    view_rect.y0,
+                    view_rect.x1,
+                    view_rect.y0 + thickness,
+                ),
+                AxisMapping1D::linear(view_rect.x0..view_rect.x1, world.x0..world.x1),
+            ),
+            GuideOrientation::Vertical => (
+                Rect::new(
+                    view_rect.x0,
+                    view_rect.y0,
+                    view_rect.x0 + thickness,
+                    view_rect.y1,
+                ),
+                AxisMapping1D::linear(view_rect.y0..view_rect.y1, world.y0..world.y1),
+            ),
+        };
+        let scale = AxisScale1D::from_mapping(&mapping, options.scale);
+        let ruler = AxisRuler1D::from_mapping(&mapping, &scale, options.marks);
+        let marks = ruler
+            .marks()
+            .iter()
+            .map(|mark| {
+                let extent = mark.mark_extent.min(thickness);
+                let at = mark.view_position;
+                let (line, label_anchor) = match orientation {
+                    GuideOrientation::Horizontal => (
+                        Line::new((at, band.y1), (at, band.y1 - extent)),
+                        Point::new(at + options.label_offset, band.y0),
+                    ),
+                    GuideOrientation::Vertical => (
+                        Line::new((band.x1, at), (band.x1 - extent, at)),
+                        Point::new(band.x0, at + options.label_offset),
+                    ),
+                };
+                ViewportRulerMark {
+                    value: mark.value,
+                    kind: mark.kind,
+                    labeled: mark.labeled,
+                    line,
+                    label_anchor,
+                }
+            })
+            .collect();
+        Self {
+            orientation,
+            band,
+            marks,
+        }
+    }
+
+    /// Returns the ruler direction.
+    #[must_use]
+    pub fn orientation(&self) -> GuideOrientation {
+        self.orientation
+    }
+
+    /// Returns the ruler strip in view coordinates, which is also its hit region.
+    #[must_use]
+    pub fn band(&self) -> Rect {
+        self.band
+    }
+
+    /// Returns the ticks in view order.
+    #[must_use]
+    pub fn marks(&self) -> &[ViewportRulerMark] {
+        &self.marks
+    }
+
+    /// Returns `true` if a view-space point is over the ruler strip.
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.band.contains(point)
+    }
+}
+
+/// An infinite guide line at a world-space position.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RulerGuide {
+    /// The guide direction.
+    pub orientation: GuideOrientation,
+    /// World y for horizontal guides, world x for vertical guides.
+    pub position: f64,
+}
+
+impl RulerGuide {
+    /// Creates a guide through a view-space point, as when dragging one out of a ruler.
+    #[must_use]
+    pub fn at_view_point(orientation: GuideOrientation, view: &Viewport2D, point: Point) -> Self {
+        let world = view.view_to_world_point(point);
+        let position = match orientation {
+            GuideOrientation::Horizontal => world.y,
+            GuideOrientation::Vertical => world.x,
+        };
+        Self {
+            orientation,
+            position,
+        }
+    }
+
+    /// Returns the guide's line across the whole view, in view coordinates.
+    #[must_use]
+    pub fn view_line(&self, view: &Viewport2D) -> Line {
+        let view_rect = view.view_rect();
+        let at = view.world_to_view_point(Point::new(self.position, self.position));
+        match self.orientation {
+            GuideOrientation::Horizontal => Line::new((view_rect.x0, at.y), (view_rect.x1, at.y)),
+            GuideOrientation::Vertical => Line::new((at.x, view_rect.y0), (at.x, view_rect.y1)),
+        }
+    }
+
+    /// Returns the view-space hit region: the view line widened by `tolerance_px` on each side.
+    #[must_use]
+    pub fn hit_region(&self, view: &Viewport2D, tolerance_px: f64) -> Rect {
+        let line = self.view_line(view);
+        let tolerance = tolerance_px.max(0.0);
+        Rect::from_points(line.p0, line.p1).inflate(tolerance, tolerance)
+    }
+}
+
+/// The guide lines of a document, with hit testing, dragging, and snap integration.
+///
+/// Guides live in world space so they stay attached to content while panning and zooming.
+/// A typical interaction:
+///
+/// 1) Pointer down on a [`ViewportRuler`]: [`RulerGuides::push`] a guide created with
+///    [`RulerGuide::at_view_point`] and start dragging it.
+/// 2) Pointer down elsewhere: [`RulerGuides::hit_test`] to pick an existing guide to drag.
+/// 3) Pointer move: [`RulerGuides::drag_to`].
+/// 4) Pointer up over the ruler it came from: [`RulerGuides::remove`] it, as in most design tools.
+/// 5) After any change, [`RulerGuides::sync_snap`] so objects snap to the guides.
+///
+/// ```rust
+/// use kurbo::{Point, Rect};
+/// use understory_guide::{GuideOrientation, RulerGuide, RulerGuides, SnapEngine};
+/// use understory_view2d::Viewport2D;
+///
+/// let mut view = Viewport2D::new(Rect::new(0.0, 0.0, 800.0, 600.0));
+/// view.set_zoom(2.0);
+///
+/// let mut guides = RulerGuides::new();
+/// let start = Point::new(300.0, 10.0);
+/// let index = guides.push(RulerGuide::at_view_point(GuideOrientation::Horizontal, &view, start));
+/// guides.drag_to(index, &view, Point::new(300.0, 200.0));
+/// assert_eq!(guides.guides()[index].position, 100.0);
+/// assert_eq!(guides.hit_test(&view, Point::new(10.0, 203.0), 4.0), Some(index));
+///
+/// let mut snap = SnapEngine::new(8.0);
+/// guides.sync_snap(&mut snap);
+/// let (snapped, _) = snap.snap_point(Point::new(40.0, 102.0), view.zoom());
+/// assert_eq!(snapped, Point::new(40.0, 100.0));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RulerGuides {
+    guides: Vec<RulerGuide>,
+}
+
+impl RulerGuides {
+    /// Creates an empty set of guides.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the guides in the order they were added.
+    #[must_use]
+    pub fn guides(&self) -> &[RulerGuide] {
+        &self.guides
+    }
+
+    /// Adds a guide and returns its index.
+    ///
+    /// Guides with a non-finite position are still stored but never hit or snapped to.
+    pub fn push(&mut self, guide: RulerGuide) -> usize {
+        self.guides.push(guide);
+        self.guides.len() - 1
+    }
+
+    /// Removes and returns the guide at `index`, shifting later indices down.
+    pub fn remove(&mut self, index: usize) -> Option<RulerGuide> {
+        (index < self.guides.len()).then(|| self.guides.remove(index))
+    }
+
+    /// Removes all guides.
+    pub fn clear(&mut self) {
+        self.guides.clear();
+    }
+
+    /// Moves the guide at `index` so it passes through a view-space point.
+    ///
+    /// Out-of-range indices and non-finite points are ignored.
+    pub fn drag_to(&mut self, index: usize, view: &Viewport2D, point: Point) {
+        if !(point.x.is_finite() && point.y.is_finite()) {
+            return;
+        }
+        if let Some(guide) = self.guides.get_mut(index) {
+            *guide = RulerGuide::at_view_point(guide.orientation, view, point);
+        }
+    }
+
+    /// Returns the index of the guide nearest to a view-space point within `tolerance_px`.
+    ///
+    /// When guides are equally near, the most recently added one wins.
+    #[must_use]
+    pub fn hit_test(&self, view: &Viewport2D, point: Point, tolerance_px: f64) -> Option<usize> {
+        let mut best: Option<(usize, f64)> = None;
+        for (index, guide) in self.guides.iter().enumerate() {
+            let line = guide.view_line(view);
+            let distance = match guide.orientation {
+                GuideOrientation::Horizontal => (point.y - line.p0.y).abs(),
+                GuideOrientation::Vertical => (point.x - line.p0.x).abs(),
+            };
+            if distance <= tolerance_px && best.is_none_or(|(_, d)| distance <= d) {
+                best = Some((index, distance));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+
+    /// Replaces the guides in a [`SnapEngine`] with these guides.
+    pub fn sync_snap(&self, snap: &mut SnapEngine) {
+        snap.clear_guides();
+        for guide in &self.guides {
+            match guide.orientation {
+                GuideOrientation::Horizontal => snap.add_horizontal_guide(guide.position),
+                GuideOrientation::Vertical => snap.add_vertical_guide(guide.position),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view() -> Viewport2D {
+        let mut view = Viewport2D::new(Rect::new(0.0, 0.0, 800.0, 600.0));
+        view.set_zoom(2.0);
+        view
+    }
+
+    #[test]
+    fn ruler_ticks_line_up_with_world_positions() {
+        let view = view();
+        for orientation in [GuideOrientation::Horizontal, GuideOrientation::Vertical] {
+            let ruler =
+                ViewportRuler::from_viewport(&view, orientation, ViewportRulerOptions::default());
+            assert!(!ruler.marks().is_empty());
+            for mark in ruler.marks() {
+                let world = Point::new(mark.value, mark.value);
+                let expected = view.world_to_view_point(world);
+                let (along, across) = match orientation {
+                    GuideOrientation::Horizontal => (mark.line.p0.x - expected.x, mark.line.p0.y),
+                    GuideOrientation::Vertical => (mark.line.p0.y - expected.y, mark.line.p0.x),
+                };
+                assert!(along.abs() < 1e-9);
+                assert_eq!(across, 20.0);
+                let tip = match orientation {
+                    GuideOrientation::Horizontal => mark.line.p1.y,
+                    GuideOrientation::Vertical => mark.line.p1.x,
+                };
+                assert!((0.0..20.0).contains(&tip));
+            }
+        }
+    }
+
+    #[test]
+    fn guide_hit_test_prefers_nearest() {
+        let view = view();
+        let mut guides = RulerGuides::new();
+        let a = guides.push(RulerGuide {
+            orientation: GuideOrientation::Vertical,
+            position: 10.0,
+        });
+        let b = guides.push(RulerGuide {
+            orientation: GuideOrientation::Vertical,
+            position: 12.0,
+        });
+        // World x 10 and 12 are view x 20 and 24 at 2x zoom.
+        assert_eq!(guides.hit_test(&view, Point::new(21.0, 50.0), 4.0), Some(a));
+        assert_eq!(guides.hit_test(&view, Point::new(23.0, 50.0), 4.0), Some(b));
+        assert_eq!(guides.hit_test(&view, Point::new(40.0, 50.0), 4.0), None);
+        assert!(
+            guides.guides()[a]
+                .hit_region(&view, 4.0)
+                .contains(Point::new(17.0, 300.0))
+        );
+
+        assert_eq!(guides.remove(a).map(|g| g.position), Some(10.0));
+        assert_eq!(guides.remove(5), None);
+        assert_eq!(guides.guides().len(), 1);
+    }
+}
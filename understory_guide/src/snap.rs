@@ -0,0 +1,387 @@
+// Copyright 2026 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Snapping of points and rectangles to grids, guides, and candidate geometry.
+
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use kurbo::common::FloatFuncs as _;
+use kurbo::{Point, Rect, Vec2};
+
+/// Kinds of snap targets, ordered from lowest to highest priority.
+///
+/// When targets of different kinds are within snap distance, the higher-priority kind wins
+/// even if it is further away. Among targets of the same kind, the nearest wins.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SnapKind {
+    /// A grid line.
+    Grid,
+    /// A guide line placed by the user.
+    Guide,
+    /// A coordinate of a candidate geometry point, such as another object's edge or center.
+    Geometry,
+}
+
+/// A regular snap grid in world coordinates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SnapGrid {
+    /// A point through which grid lines pass on both axes.
+    pub origin: Point,
+    /// Grid line spacing on each axis. Non-positive or non-finite spacings disable that axis.
+    pub spacing: Vec2,
+}
+
+impl SnapGrid {
+    /// Creates a square grid through the world origin.
+    #[must_use]
+    pub fn uniform(spacing: f64) -> Self {
+        Self {
+            origin: Point::ZERO,
+            spacing: Vec2::new(spacing, spacing),
+        }
+    }
+}
+
+/// The snap applied along one axis.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AxisSnapHit {
+    /// The kind of target that was snapped to.
+    pub kind: SnapKind,
+    /// The world coordinate of the target line, suitable for drawing a snap indicator.
+    pub value: f64,
+    /// How far the input moved along this axis, in world units.
+    pub distance: f64,
+}
+
+/// The result of a snap query, per axis.
+///
+/// Axes snap independently, so a point may snap horizontally to a guide and vertically to the
+/// grid, or only along one axis.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SnapHit {
+    /// The snap applied to the x coordinate, if any.
+    pub x: Option<AxisSnapHit>,
+    /// The snap applied to the y coordinate, if any.
+    pub y: Option<AxisSnapHit>,
+}
+
+impl SnapHit {
+    /// Returns `true` if neither axis snapped.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.x.is_none() && self.y.is_none()
+    }
+
+    /// Returns the world-space offset applied by the snap.
+    #[must_use]
+    pub fn offset(&self) -> Vec2 {
+        Vec2::new(
+            self.x.map_or(0.0, |hit| hit.distance),
+            self.y.map_or(0.0, |hit| hit.distance),
+        )
+    }
+}
+
+/// Snaps points and rectangles to a grid, guides, and candidate geometry.
+///
+/// Targets are stored in world coordinates, while the snap distance is given in view pixels so
+/// that snapping feels the same at every zoom level. The same engine can serve pan snapping in
+/// a transform pipeline and object dragging in an editor.
+///
+/// ```rust
+/// use kurbo::{Point, Rect};
+/// use understory_guide::{SnapEngine, SnapGrid, SnapKind};
+///
+/// let mut snap = SnapEngine::new(8.0);
+/// snap.set_grid(Some(SnapGrid::uniform(10.0)));
+/// snap.add_vertical_guide(33.0);
+///
+/// // At 2x zoom, 8 view pixels are 4 world units.
+/// let (snapped, hit) = snap.snap_point(Point::new(31.0, 22.0), 2.0);
+/// assert_eq!(snapped, Point::new(33.0, 20.0));
+/// assert_eq!(hit.x.unwrap().kind, SnapKind::Guide);
+/// assert_eq!(hit.y.unwrap().kind, SnapKind::Grid);
+///
+/// // Rectangles snap by their edges or center, keeping their size.
+/// let (rect, _) = snap.snap_rect(Rect::new(1.0, 1.0, 21.0, 11.0), 2.0);
+/// assert_eq!(rect, Rect::new(0.0, 0.0, 20.0, 10.0));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SnapEngine {
+    grid: Option<SnapGrid>,
+    vertical_guides: Vec<f64>,
+    horizontal_guides: Vec<f64>,
+    points: Vec<Point>,
+    distance_px: f64,
+}
+
+impl SnapEngine {
+    /// Creates an engine with no targets that snaps within `distance_px` view pixels.
+    #[must_use]
+    pub fn new(distance_px: f64) -> Self {
+        Self {
+            distance_px,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the snap distance in view pixels.
+    #[must_use]
+    pub fn distance_px(&self) -> f64 {
+        self.distance_px
+    }
+
+    /// Sets the snap distance in view pixels.
+    ///
+    /// Non-finite or negative values are ignored.
+    pub fn set_distance_px(&mut self, distance_px: f64) {
+        if distance_px.is_finite() && distance_px >= 0.0 {
+            self.distance_px = distance_px;
+        }
+    }
+
+    /// Returns the snap grid, if any.
+    #[must_use]
+    pub fn grid(&self) -> Option<SnapGrid> {
+        self.grid
+    }
+
+    /// Sets or clears the snap grid.
+    pub fn set_grid(&mut self, grid: Option<SnapGrid>) {
+        self.grid = grid;
+    }
+
+    /// Adds a vertical guide line at world `x`.
+    ///
+    /// Non-finite values are ignored.
+    pub fn add_vertical_guide(&mut self, x: f64) {
+        if x.is_finite() {
+            self.vertical_guides.push(x);
+        }
+    }
+
+    /// Adds a horizontal guide line at world `y`.
+    ///
+    /// Non-finite values are ignored.
+    pub fn add_horizontal_guide(&mut self, y: f64) {
+        if y.is_finite() {
+            self.horizontal_guides.push(y);
+        }
+    }
+
+    /// Removes all guide lines.
+    pub fn clear_guides(&mut self) {
+        self.vertical_guides.clear();
+        self.horizontal_guides.clear();
+    }
+
+    /// Adds a candidate geometry point in world coordinates.
+    ///
+    /// Its x and y coordinates act as alignment lines on their own axes. Non-finite points are
+    /// ignored.
+    pub fn add_point(&mut self, point: Point) {
+        if point.x.is_finite() && point.y.is_finite() {
+            self.points.push(point);
+        }
+    }
+
+    /// Adds the four corners and center of a candidate rectangle in world coordinates.
+    pub fn add_rect(&mut self, rect: Rect) {
+        self.add_point(Point::new(rect.x0, rect.y0));
+        self.add_point(Point::new(rect.x1, rect.y0));
+        self.add_point(Point::new(rect.x1, rect.y1));
+        self.add_point(Point::new(rect.x0, rect.y1));
+        self.add_point(rect.center());
+    }
+
+    /// Removes all candidate geometry points.
+    pub fn clear_points(&mut self) {
+        self.points.clear();
+    }
+
+    /// Snaps a world-space point, given the current view zoom.
+    ///
+    /// Returns the snapped point and which targets it snapped to. Invalid zooms or points
+    /// leave the point unchanged.
+    #[must_use]
+    pub fn snap_point(&self, point: Point, zoom: f64) -> (Point, SnapHit) {
+        let Some(tolerance) = self.world_tolerance(zoom) else {
+            return (point, SnapHit::default());
+        };
+        let hit = SnapHit {
+            x: self.snap_axis(&[point.x], tolerance, Axis::X),
+            y: self.snap_axis(&[point.y], tolerance, Axis::Y),
+        };
+        (point + hit.offset(), hit)
+    }
+
+    /// Snaps a world-space rectangle by its edges or center, given the current view zoom.
+    ///
+    /// The rectangle is translated, never resized. Returns the snapped rectangle and which
+    /// targets it snapped to. Invalid zooms or rectangles leave it unchanged.
+    #[must_use]
+    pub fn snap_rect(&self, rect: Rect, zoom: f64) -> (Rect, SnapHit) {
+        let Some(tolerance) = self.world_tolerance(zoom) else {
+            return (rect, SnapHit::default());
+        };
+        let center = rect.center();
+        let hit = SnapHit {
+            x: self.snap_axis(&[rect.x0, center.x, rect.x1], tolerance, Axis::X),
+            y: self.snap_axis(&[rect.y0, center.y, rect.y1], tolerance, Axis::Y),
+        };
+        (rect + hit.offset(), hit)
+    }
+
+    fn world_tolerance(&self, zoom: f64) -> Option<f64> {
+        let zoom_valid = zoom.is_finite() && zoom > 0.0;
+        let distance_valid = self.distance_px.is_finite() && self.distance_px >= 0.0;
+        (zoom_valid && distance_valid).then(|| self.distance_px / zoom)
+    }
+
+    /// Finds the best snap for any of `features` (coordinates along `axis`).
+    fn snap_axis(&self, features: &[f64], tolerance: f64, axis: Axis) -> Option<AxisSnapHit> {
+        let mut best: Option<AxisSnapHit> = None;
+        let mut consider = |kind: SnapKind, feature: f64, value: f64| {
+            let distance = value - feature;
+            if distance.abs() > tolerance {
+                return;
+            }
+            let better = best.is_none_or(|b| {
+                kind > b.kind || (kind == b.kind && distance.abs() < b.distance.abs())
+            });
+            if better {
+                best = Some(AxisSnapHit {
+                    kind,
+                    value,
+                    distance,
+                });
+            }
+        };
+
+        let guides = match axis {
+            Axis::X => &self.vertical_guides,
+            Axis::Y => &self.horizontal_guides,
+        };
+        for &feature in features {
+            if !feature.is_finite() {
+                continue;
+            }
+            if let Some(grid) = self.grid {
+                let (origin, spacing) = match axis {
+                    Axis::X => (grid.origin.x, grid.spacing.x),
+                    Axis::Y => (grid.origin.y, grid.spacing.y),
+                };
+                if spacing.is_finite() && spacing > 0.0 && origin.is_finite() {
+                    let line = origin + ((feature - origin) / spacing).round() * spacing;
+                    consider(SnapKind::Grid, feature, line);
+                }
+            }
+            for &guide in guides {
+                consider(SnapKind::Guide, feature, guide);
+            }
+            for point in &self.points {
+                let value = match axis {
+                    Axis::X => point.x,
+                    Axis::Y => point.y,
+                };
+                consider(SnapKind::Geometry, feature, value);
+            }
+        }
+        best
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Axis {
+    X,
+    Y,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_distance_scales_with_zoom() {
+        let mut snap = SnapEngine::new(6.0);
+        snap.set_grid(Some(SnapGrid::uniform(10.0)));
+        let p = Point::new(14.0, 50.0);
+        // At zoom 1, 4 world units is within 6 px.
+        assert_eq!(snap.snap_point(p, 1.0).0, Point::new(10.0, 50.0));
+        // At zoom 4, 6 px is 1.5 world units, so x stays put.
+        let (snapped, hit) = snap.snap_point(p, 4.0);
+        assert_eq!(snapped, p);
+        assert!(hit.x.is_none());
+        assert_eq!(hit.y.map(|h| h.value), Some(50.0));
+    }
+
+    #[test]
+    fn higher_priority_kind_wins_over_nearer_target() {
+        let mut snap = SnapEngine::new(5.0);
+        snap.set_grid(Some(SnapGrid::uniform(10.0)));
+        snap.add_vertical_guide(13.0);
+        snap.add_point(Point::new(16.0, 0.0));
+        let (snapped, hit) = snap.snap_point(Point::new(12.0, 100.0), 1.0);
+        assert_eq!(snapped.x, 16.0);
+        let x = hit.x.unwrap();
+        assert_eq!(x.kind, SnapKind::Geometry);
+        assert_eq!(x.distance, 4.0);
+
+        snap.clear_points();
+        let (snapped, hit) = snap.snap_point(Point::new(12.0, 100.0), 1.0);
+        assert_eq!(snapped.x, 13.0);
+        assert_eq!(hit.x.unwrap().kind, SnapKind::Guide);
+    }
+
+    #[test]
+    fn rect_snaps_nearest_feature_without_resizing() {
+        let mut snap = SnapEngine::new(3.0);
+        snap.add_rect(Rect::new(100.0, 100.0, 140.0, 120.0));
+        // Left edge is 2 away from 100 and right edge is 1 away from 140: the right edge wins.
+        let (rect, hit) = snap.snap_rect(Rect::new(98.0, 0.0, 139.0, 10.0), 1.0);
+        assert_eq!(rect, Rect::new(99.0, 0.0, 140.0, 10.0));
+        assert_eq!(hit.x.unwrap().value, 140.0);
+        assert!(hit.y.is_none());
+        // Centers align too.
+        let (rect, _) = snap.snap_rect(Rect::new(0.0, 99.0, 10.0, 119.0), 1.0);
+        assert_eq!(rect.center().y, 110.0);
+    }
+
+    // Every corner of a candidate rectangle is a snap target, not just two opposite ones.
+    #[test]
+    fn rect_corners_are_snap_targets() {
+        let mut snap = SnapEngine::new(3.0);
+        snap.add_rect(Rect::new(100.0, 100.0, 140.0, 120.0));
+        for corner in [
+            Point::new(100.0, 100.0),
+            Point::new(140.0, 100.0),
+            Point::new(140.0, 120.0),
+            Point::new(100.0, 120.0),
+        ] {
+            let (snapped, hit) = snap.snap_point(corner + Vec2::new(1.5, -2.0), 1.0);
+            assert_eq!(snapped, corner);
+            assert_eq!(hit.x.unwrap().kind, SnapKind::Geometry);
+            assert_eq!(hit.y.unwrap().kind, SnapKind::Geometry);
+        }
+    }
+
+    #[test]
+    fn invalid_inputs_do_not_snap() {
+        let mut snap = SnapEngine::new(5.0);
+        snap.set_grid(Some(SnapGrid {
+            origin: Point::ZERO,
+            spacing: Vec2::new(0.0, f64::NAN),
+        }));
+        snap.add_vertical_guide(f64::NAN);
+        snap.add_point(Point::new(f64::INFINITY, 0.0));
+        let p = Point::new(1.0, 1.0);
+        assert!(snap.snap_point(p, 1.0).1.is_empty());
+        snap.add_horizontal_guide(0.0);
+        assert!(snap.snap_point(p, 0.0).1.is_empty());
+        assert!(snap.snap_point(p, f64::NAN).1.is_empty());
+        assert_eq!(snap.snap_point(p, 1.0).0, Point::new(1.0, 0.0));
+        snap.set_distance_px(-1.0);
+        assert_eq!(snap.distance_px(), 5.0);
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use kurbo::{Insets, Size};
+
+/// The range of sizes a node may take, passed from a container to its children.
+///
+/// Constructors keep `min <= max` on both axes. Maximums may be infinite to leave an axis
+/// unbounded, for example the main axis of a scrolling column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraints {
+    /// Smallest allowed size.
+    pub min: Size,
+    /// Largest allowed size; components may be infinite.
+    pub max: Size,
+}
+
+impl Constraints {
+    /// Constraints that accept any size.
+    pub const UNBOUNDED: Self = Self {
+        min: Size::ZERO,
+        max: Size::new(f64::INFINITY, f64::INFINITY),
+    };
+
+    /// Constraints that only accept `size`.
+    ///
+    /// Negative or NaN components are treated as zero.
+    #[must_use]
+    pub fn tight(size: Size) -> Self {
+        let size = Size::new(sanitize(size.width), sanitize(size.height));
+        Self {
+            min: size,
+            max: size,
+        }
+    }
+
+    /// Constraints that accept any size up to `max`.
+    ///
+    /// Negative or NaN components are treated as zero; infinite components leave an axis
+    /// unbounded.
+    #[must_use]
+    pub fn loose(max: Size) -> Self {
+        Self {
+            min: Size::ZERO,
+            max: Size::new(sanitize(max.width), sanitize(max.height)),
+        }
+    }
+
+    /// Returns the same maximum with the minimum removed.
+    #[must_use]
+    pub fn loosen(self) -> Self {
+        Self {
+            min: Size::ZERO,
+            max: self.max,
+        }
+    }
+
+    /// Returns `true` if the minimum and maximum are equal on both axes.
+    #[must_use]
+    pub fn is_tight(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// Clamps `size` into the constraints.
+    #[must_use]
+    pub fn constrain(&self, size: Size) -> Size {
+        Size::new(
+            clamp(size.width, self.min.width, self.max.width),
+            clamp(size.height, self.min.height, self.max.height),
+        )
+    }
+
+    /// Shrinks the constraints by `insets`, as when entering a node's padding.
+    #[must_use]
+    pub fn deflate(&self, insets: Insets) -> Self {
+        let dx = sanitize(insets.x0) + sanitize(insets.x1);
+        let dy = sanitize(insets.y0) + sanitize(insets.y1);
+        Self {
+            min: Size::new(
+                (self.min.width - dx).max(0.0),
+                (self.min.height - dy).max(0.0),
+            ),
+            max: Size::new(
+                (self.max.width - dx).max(0.0),
+                (self.max.height - dy).max(0.0),
+            ),
+        }
+    }
+}
+
+impl Default for Constraints {
+    fn default() -> Self {
+        Self::UNBOUNDED
+    }
+}
+
+/// Maps negative and NaN values to zero, keeping infinities.
+pub(crate) fn sanitize(value: f64) -> f64 {
+    if value > 0.0 { value } else { 0.0 }
+}
+
+/// Clamps without panicking on inverted ranges or NaN; the lower bound wins.
+pub(crate) fn clamp(value: f64, min: f64, max: f64) -> f64 {
+    let value = if value.is_nan() { min } else { value };
+    value.min(max).max(min)
+}
@@ -0,0 +1,165 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::style::{Align, Axis, Justify};
+
+/// How a node arranges its children.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Container {
+    /// No children are arranged; the node's size comes from its style and the measure
+    /// function passed to [`LayoutTree::compute_with`](crate::LayoutTree::compute_with).
+    #[default]
+    Leaf,
+    /// A row or column, see [`Flex`].
+    Flex(Flex),
+    /// Children on top of each other, see [`Stack`].
+    Stack(Stack),
+    /// Children in equal-width columns, see [`Grid`].
+    Grid(Grid),
+}
+
+impl From<Flex> for Container {
+    fn from(flex: Flex) -> Self {
+        Self::Flex(flex)
+    }
+}
+
+impl From<Stack> for Container {
+    fn from(stack: Stack) -> Self {
+        Self::Stack(stack)
+    }
+}
+
+impl From<Grid> for Container {
+    fn from(grid: Grid) -> Self {
+        Self::Grid(grid)
+    }
+}
+
+/// Children in a row or column, sized by their [`FlexItem`](crate::FlexItem) properties.
+///
+/// Each child is first measured at its basis size. Leftover main-axis space is shared among
+/// children by `grow`; if children overflow, they give up space in proportion to `shrink`
+/// times their basis. The container is as long as its children and gaps, or fills the
+/// available main-axis space when any child grows.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Flex {
+    /// Main axis along which children are placed.
+    pub axis: Axis,
+    /// Space between adjacent children.
+    pub gap: f64,
+    /// Distribution of leftover main-axis space.
+    pub justify: Justify,
+    /// Default cross-axis alignment of children.
+    pub cross_align: Align,
+}
+
+impl Flex {
+    /// A left-to-right row with stretched children.
+    #[must_use]
+    pub fn row() -> Self {
+        Self::new(Axis::Horizontal)
+    }
+
+    /// A top-to-bottom column with stretched children.
+    #[must_use]
+    pub fn column() -> Self {
+        Self::new(Axis::Vertical)
+    }
+
+    fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            gap: 0.0,
+            justify: Justify::Start,
+            cross_align: Align::Stretch,
+        }
+    }
+
+    /// Returns the container with a different gap between children.
+    #[must_use]
+    pub fn with_gap(mut self, gap: f64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Returns the container with a different main-axis distribution.
+    #[must_use]
+    pub fn with_justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Returns the container with a different default cross-axis alignment.
+    #[must_use]
+    pub fn with_cross_align(mut self, cross_align: Align) -> Self {
+        self.cross_align = cross_align;
+        self
+    }
+}
+
+/// Children layered on top of each other, such as a badge over an icon or an overlay.
+///
+/// The stack is as large as its largest child. Children are listed back to front.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stack {
+    /// Horizontal placement of children.
+    pub horizontal: Align,
+    /// Vertical placement of children.
+    pub vertical: Align,
+}
+
+impl Stack {
+    /// A stack with the given child placement.
+    #[must_use]
+    pub fn new(horizontal: Align, vertical: Align) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+/// Children in row-major order in a fixed number of equal-width columns.
+///
+/// Columns share the available width; when it is unbounded they take the widest child's
+/// natural width. Each row is as tall as its tallest child unless `row_height` is set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Grid {
+    /// Number of columns; zero is treated as one.
+    pub columns: usize,
+    /// Space between columns.
+    pub column_gap: f64,
+    /// Space between rows.
+    pub row_gap: f64,
+    /// Fixed height of every row.
+    pub row_height: Option<f64>,
+}
+
+impl Grid {
+    /// A grid with `columns` columns, no gaps, and content-sized rows.
+    #[must_use]
+    pub fn new(columns: usize) -> Self {
+        Self {
+            columns,
+            column_gap: 0.0,
+            row_gap: 0.0,
+            row_height: None,
+        }
+    }
+
+    /// Returns the grid with different column and row gaps.
+    #[must_use]
+    pub fn with_gaps(mut self, column_gap: f64, row_gap: f64) -> Self {
+        self.column_gap = column_gap;
+        self.row_gap = row_gap;
+        self
+    }
+
+    /// Returns the grid with a fixed row height.
+    #[must_use]
+    pub fn with_row_height(mut self, row_height: f64) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// After you edit the crate's doc comment, run this command, then check README.md for any missing links
+// cargo rdme --workspace-project=understory_layout --heading-base-level=0
+
+//! Understory Layout: minimal layout containers that produce node rectangles.
+//!
+//! `understory_box_tree` deliberately does not lay anything out; it expects positions and sizes
+//! from upstream. This crate is a small upstream for demos and simple UIs:
+//!
+//! - [`LayoutTree`]: nodes described by a [`LayoutNode`], arranged by [`LayoutTree::compute`]
+//!   and read back with [`LayoutTree::local_rect`] or [`LayoutTree::absolute_rect`].
+//! - [`Flex`]: rows and columns with gaps, [`Justify`], cross-axis [`Align`], and per-child
+//!   grow, shrink, and basis ([`FlexItem`]).
+//! - [`Stack`]: children layered on top of each other, aligned on both axes.
+//! - [`Grid`]: a fixed number of equal-width columns with content-sized or fixed rows.
+//! - [`Style`]: fixed, minimum, and maximum sizes plus padding for any node.
+//!
+//! Layout is a single recursive pass: each container hands its children [`Constraints`],
+//! measures them, and positions them. Leaves are sized by their style, or by a measure
+//! function given to [`LayoutTree::compute_with`] for content such as text.
+//!
+//! It does not own text measurement, scrolling, baseline alignment, wrapping flex lines,
+//! incremental relayout, or rendering.
+//!
+//! ## Feeding a box tree
+//!
+//! Local rectangles are relative to the parent node, so they map directly onto
+//! box tree nodes with a translation as their local transform:
+//!
+//! ```rust
+//! use kurbo::{Affine, Rect, Size};
+//! use understory_box_tree::{LocalNode, Tree};
+//! use understory_layout::{Flex, FlexItem, LayoutNode, LayoutTree, Style};
+//!
+//! let mut layout = LayoutTree::new();
+//! let toolbar = layout.insert(None, LayoutNode::container(Flex::row().with_gap(4.0)));
+//! let button = layout.insert(Some(toolbar), LayoutNode::leaf(Style::fixed(Size::new(24.0, 24.0))));
+//! let search = layout.insert(
+//!     Some(toolbar),
+//!     LayoutNode::leaf(Style { flex: FlexItem::fill(1.0), ..Style::default() }),
+//! );
+//! layout.compute(toolbar, Size::new(300.0, 24.0));
+//! assert_eq!(layout.local_rect(search), Some(Rect::new(28.0, 0.0, 300.0, 24.0)));
+//!
+//! let mut boxes = Tree::new();
+//! let mut insert = |parent, id| {
+//!     let rect = layout.local_rect(id).unwrap();
+//!     let local = LocalNode {
+//!         local_bounds: rect.with_origin((0.0, 0.0)),
+//!         local_transform: Affine::translate(rect.origin().to_vec2()),
+//!         ..LocalNode::default()
+//!     };
+//!     boxes.insert(parent, local)
+//! };
+//! let root = insert(None, toolbar);
+//! insert(Some(root), button);
+//! let search_box = insert(Some(root), search);
+//! boxes.commit();
+//! assert_eq!(
+//!     boxes.world_bounds(search_box),
+//!     Some(Rect::new(28.0, 0.0, 300.0, 24.0))
+//! );
+//! ```
+//!
+//! This crate is `no_std` and uses `alloc`.
+
+#![no_std]
+
+extern crate alloc;
+
+mod constraints;
+mod container;
+mod style;
+mod tree;
+
+pub use constraints::Constraints;
+pub use container::{Container, Flex, Grid, Stack};
+pub use style::{Align, Axis, FlexItem, Justify, Style};
+pub use tree::{LayoutId, LayoutNode, LayoutTree};
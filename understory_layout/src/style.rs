@@ -0,0 +1,214 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use kurbo::{Insets, Point, Size};
+
+use crate::constraints::{Constraints, clamp, sanitize};
+
+/// A layout direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Left to right.
+    #[default]
+    Horizontal,
+    /// Top to bottom.
+    Vertical,
+}
+
+impl Axis {
+    pub(crate) fn flip(self) -> Self {
+        match self {
+            Self::Horizontal => Self::Vertical,
+            Self::Vertical => Self::Horizontal,
+        }
+    }
+
+    pub(crate) fn main(self, size: Size) -> f64 {
+        match self {
+            Self::Horizontal => size.width,
+            Self::Vertical => size.height,
+        }
+    }
+
+    pub(crate) fn cross(self, size: Size) -> f64 {
+        match self {
+            Self::Horizontal => size.height,
+            Self::Vertical => size.width,
+        }
+    }
+
+    pub(crate) fn size(self, main: f64, cross: f64) -> Size {
+        match self {
+            Self::Horizontal => Size::new(main, cross),
+            Self::Vertical => Size::new(cross, main),
+        }
+    }
+
+    pub(crate) fn point(self, main: f64, cross: f64) -> Point {
+        match self {
+            Self::Horizontal => Point::new(main, cross),
+            Self::Vertical => Point::new(cross, main),
+        }
+    }
+}
+
+/// Placement of a child along an axis where it may be smaller than the space given to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Place at the start (left or top).
+    #[default]
+    Start,
+    /// Center in the available space.
+    Center,
+    /// Place at the end (right or bottom).
+    End,
+    /// Size the child to fill the available space.
+    ///
+    /// Children with a fixed size along the axis are placed at the start instead.
+    Stretch,
+}
+
+impl Align {
+    /// Offset of a child of length `child` within `space`.
+    pub(crate) fn offset(self, space: f64, child: f64) -> f64 {
+        match self {
+            Self::Start | Self::Stretch => 0.0,
+            Self::Center => (space - child) * 0.5,
+            Self::End => space - child,
+        }
+    }
+}
+
+/// How a flex container distributes leftover space along its main axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// Pack children at the start.
+    #[default]
+    Start,
+    /// Pack children in the center.
+    Center,
+    /// Pack children at the end.
+    End,
+    /// Put the first child at the start, the last at the end, and spread the rest evenly.
+    SpaceBetween,
+}
+
+/// How a child of a flex container grows and shrinks along the main axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlexItem {
+    /// Share of the leftover space this child takes; `0.0` keeps its basis size.
+    pub grow: f64,
+    /// How much this child gives up when children overflow, weighted by its basis size.
+    pub shrink: f64,
+    /// Main-axis size before growing or shrinking; `None` uses the child's natural size.
+    pub basis: Option<f64>,
+}
+
+impl Default for FlexItem {
+    /// No growth, shrinking enabled, natural basis.
+    fn default() -> Self {
+        Self {
+            grow: 0.0,
+            shrink: 1.0,
+            basis: None,
+        }
+    }
+}
+
+impl FlexItem {
+    /// A flex item that takes `grow` shares of the leftover space, starting from zero size.
+    ///
+    /// `FlexItem::fill(1.0)` on several children splits the space evenly between them.
+    #[must_use]
+    pub fn fill(grow: f64) -> Self {
+        Self {
+            grow,
+            shrink: 1.0,
+            basis: Some(0.0),
+        }
+    }
+}
+
+/// Sizing and placement properties of a layout node.
+///
+/// Sizes are in the same units as the available size passed to
+/// [`LayoutTree::compute`](crate::LayoutTree::compute). Constraints from the parent always
+/// win over the style, so a fixed `width` wider than the container is cut to fit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Style {
+    /// Fixed width, overriding the natural width.
+    pub width: Option<f64>,
+    /// Fixed height, overriding the natural height.
+    pub height: Option<f64>,
+    /// Minimum size, including padding.
+    pub min_size: Size,
+    /// Maximum size, including padding; components may be infinite.
+    pub max_size: Size,
+    /// Space between the node's edges and its content.
+    pub padding: Insets,
+    /// Grow and shrink behavior inside a [`Flex`](crate::Flex) container.
+    pub flex: FlexItem,
+    /// Cross-axis alignment inside a [`Flex`](crate::Flex) container, overriding the
+    /// container's [`Flex::cross_align`](crate::Flex::cross_align).
+    pub align_self: Option<Align>,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            width: None,
+            height: None,
+            min_size: Size::ZERO,
+            max_size: Size::new(f64::INFINITY, f64::INFINITY),
+            padding: Insets::ZERO,
+            flex: FlexItem::default(),
+            align_self: None,
+        }
+    }
+}
+
+impl Style {
+    /// A style with a fixed size.
+    #[must_use]
+    pub fn fixed(size: Size) -> Self {
+        Self {
+            width: Some(size.width),
+            height: Some(size.height),
+            ..Self::default()
+        }
+    }
+
+    /// Narrows the parent's constraints by the style's size properties.
+    pub(crate) fn resolve(&self, parent: Constraints) -> Constraints {
+        let axis = |fixed: Option<f64>, min: f64, max: f64, parent_min: f64, parent_max: f64| {
+            let (lo, hi) = match fixed.filter(|v| v.is_finite()) {
+                Some(fixed) => (sanitize(fixed), sanitize(fixed)),
+                None => (
+                    sanitize(min),
+                    if max.is_nan() { f64::INFINITY } else { max },
+                ),
+            };
+            let lo = clamp(lo, parent_min, parent_max);
+            let hi = clamp(hi, parent_min, parent_max).max(lo);
+            (lo, hi)
+        };
+        let (min_w, max_w) = axis(
+            self.width,
+            self.min_size.width,
+            self.max_size.width,
+            parent.min.width,
+            parent.max.width,
+        );
+        let (min_h, max_h) = axis(
+            self.height,
+            self.min_size.height,
+            self.max_size.height,
+            parent.min.height,
+            parent.max.height,
+        );
+        Constraints {
+            min: Size::new(min_w, min_h),
+            max: Size::new(max_w, max_h),
+        }
+    }
+}
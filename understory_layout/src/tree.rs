@@ -0,0 +1,718 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec::Vec;
+use kurbo::{Point, Rect, Size, Vec2};
+
+use crate::constraints::{Constraints, sanitize};
+use crate::container::{Container, Flex, Grid, Stack};
+use crate::style::{Align, Axis, Justify, Style};
+
+/// Identifier of a node in a [`LayoutTree`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LayoutId(u32);
+
+impl LayoutId {
+    const fn idx(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A node description: how it is sized and how it arranges its children.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LayoutNode {
+    /// Sizing and placement properties.
+    pub style: Style,
+    /// Arrangement of the node's children.
+    pub container: Container,
+}
+
+impl LayoutNode {
+    /// A leaf with the given style.
+    #[must_use]
+    pub fn leaf(style: Style) -> Self {
+        Self {
+            style,
+            container: Container::Leaf,
+        }
+    }
+
+    /// A container with a default style.
+    #[must_use]
+    pub fn container(container: impl Into<Container>) -> Self {
+        Self {
+            style: Style::default(),
+            container: container.into(),
+        }
+    }
+
+    /// Returns the node with a different style.
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    parent: Option<LayoutId>,
+    children: Vec<LayoutId>,
+    layout: LayoutNode,
+    /// Result of the last layout, relative to the parent's origin.
+    rect: Rect,
+}
+
+/// A tree of layout nodes and the rectangles computed for them.
+///
+/// Nodes are appended with [`LayoutTree::insert`] and arranged by [`LayoutTree::compute`],
+/// a single recursive pass in which each container sizes its children under
+/// [`Constraints`] and then positions them. Results are read back with
+/// [`LayoutTree::local_rect`] (relative to the parent) or [`LayoutTree::absolute_rect`]
+/// (relative to the root).
+///
+/// There is no caching between passes: each `compute` lays out the whole subtree again,
+/// and flex children that grow, shrink, or stretch are laid out a second time at their
+/// final size.
+#[derive(Clone, Debug, Default)]
+pub struct LayoutTree {
+    nodes: Vec<Node>,
+}
+
+impl LayoutTree {
+    /// Create an empty tree.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of nodes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the tree has no nodes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Remove all nodes. Previously returned identifiers must not be used afterwards.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    /// Append a node as the last child of `parent`, or as a new root if `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` does not belong to this tree.
+    pub fn insert(&mut self, parent: Option<LayoutId>, node: LayoutNode) -> LayoutId {
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "LayoutId uses 32-bit indices by design."
+        )]
+        let id = LayoutId(self.nodes.len() as u32);
+        if let Some(parent) = parent {
+            self.nodes[parent.idx()].children.push(id);
+        }
+        self.nodes.push(Node {
+            parent,
+            children: Vec::new(),
+            layout: node,
+            rect: Rect::ZERO,
+        });
+        id
+    }
+
+    /// Returns the node description, or `None` for unknown identifiers.
+    #[must_use]
+    pub fn node(&self, id: LayoutId) -> Option<&LayoutNode> {
+        self.nodes.get(id.idx()).map(|node| &node.layout)
+    }
+
+    /// Returns the node description mutably, for changing style or container before the next
+    /// [`LayoutTree::compute`].
+    pub fn node_mut(&mut self, id: LayoutId) -> Option<&mut LayoutNode> {
+        self.nodes.get_mut(id.idx()).map(|node| &mut node.layout)
+    }
+
+    /// Returns the parent of a node, or `None` for roots and unknown identifiers.
+    #[must_use]
+    pub fn parent_of(&self, id: LayoutId) -> Option<LayoutId> {
+        self.nodes.get(id.idx()).and_then(|node| node.parent)
+    }
+
+    /// Returns the children of a node in order, or an empty slice for unknown identifiers.
+    #[must_use]
+    pub fn children_of(&self, id: LayoutId) -> &[LayoutId] {
+        self.nodes
+            .get(id.idx())
+            .map_or(&[], |node| node.children.as_slice())
+    }
+
+    /// Returns the rectangle of a node relative to its parent's top-left corner, as of the
+    /// last [`LayoutTree::compute`] covering it.
+    ///
+    /// Padding is part of the parent's rectangle, so children of a padded container start at
+    /// the padding offset. Roots are placed at the origin.
+    #[must_use]
+    pub fn local_rect(&self, id: LayoutId) -> Option<Rect> {
+        self.nodes.get(id.idx()).map(|node| node.rect)
+    }
+
+    /// Returns the rectangle of a node relative to its root's top-left corner.
+    #[must_use]
+    pub fn absolute_rect(&self, id: LayoutId) -> Option<Rect> {
+        let mut rect = self.local_rect(id)?;
+        let mut current = self.parent_of(id);
+        while let Some(parent) = current {
+            rect = rect + self.nodes[parent.idx()].rect.origin().to_vec2();
+            current = self.parent_of(parent);
+        }
+        Some(rect)
+    }
+
+    /// Lay out the subtree at `root` to fill `available` space.
+    ///
+    /// The root fills finite components of `available`, like a window's content, and is sized
+    /// to its content along infinite ones. Leaves take the smallest size their style and
+    /// constraints allow, which suits fixed-size boxes and spacers. Use
+    /// [`LayoutTree::compute_with`] to measure content such as text.
+    pub fn compute(&mut self, root: LayoutId, available: Size) {
+        self.compute_with(root, available, |_, constraints| constraints.min);
+    }
+
+    /// Lay out the subtree at `root` to fill `available` space, measuring leaves with `measure`.
+    ///
+    /// `measure` receives a leaf and the constraints for its content (inside its padding)
+    /// and returns the content's preferred size, which is then clamped to the constraints.
+    /// It may be called more than once per leaf and pass.
+    pub fn compute_with(
+        &mut self,
+        root: LayoutId,
+        available: Size,
+        mut measure: impl FnMut(LayoutId, Constraints) -> Size,
+    ) {
+        if root.idx() >= self.nodes.len() {
+            return;
+        }
+        let mut constraints = Constraints::loose(available);
+        let fill = |max: f64| if max.is_finite() { max } else { 0.0 };
+        constraints.min = Size::new(fill(constraints.max.width), fill(constraints.max.height));
+        let size = self.layout(root, constraints, &mut measure);
+        self.nodes[root.idx()].rect = Rect::from_origin_size(Point::ORIGIN, size);
+    }
+
+    /// Size `id` under `constraints`, positioning its descendants, and return its size.
+    ///
+    /// The node's own origin is set by the caller.
+    fn layout(
+        &mut self,
+        id: LayoutId,
+        constraints: Constraints,
+        measure: &mut Measure<'_>,
+    ) -> Size {
+        let LayoutNode { style, container } = self.nodes[id.idx()].layout;
+        let outer = style.resolve(constraints);
+        let inner = outer.deflate(style.padding);
+        let origin = Point::new(sanitize(style.padding.x0), sanitize(style.padding.y0));
+        let content = match container {
+            Container::Leaf => inner.constrain(measure(id, inner)),
+            Container::Flex(flex) => self.layout_flex(id, flex, inner, origin, measure),
+            Container::Stack(stack) => self.layout_stack(id, stack, inner, origin, measure),
+            Container::Grid(grid) => self.layout_grid(id, grid, inner, origin, measure),
+        };
+        let padding = Size::new(
+            origin.x + sanitize(style.padding.x1),
+            origin.y + sanitize(style.padding.y1),
+        );
+        outer.constrain(content + padding)
+    }
+
+    fn place(&mut self, id: LayoutId, origin: Point, size: Size) {
+        self.nodes[id.idx()].rect = Rect::from_origin_size(origin, size);
+    }
+
+    fn layout_flex(
+        &mut self,
+        id: LayoutId,
+        flex: Flex,
+        inner: Constraints,
+        origin: Point,
+        measure: &mut Measure<'_>,
+    ) -> Size {
+        let children = self.nodes[id.idx()].children.clone();
+        if children.is_empty() {
+            return inner.min;
+        }
+        let axis = flex.axis;
+        let (min_main, max_main) = (axis.main(inner.min), axis.main(inner.max));
+        let max_cross = axis.cross(inner.max);
+        let gap = finite_or_zero(flex.gap);
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "Child counts are far below 2^52."
+        )]
+        let gaps = gap * (children.len() - 1) as f64;
+
+        let child_constraints = |main: Option<f64>, cross: Option<f64>| {
+            let (min_main, max_main) = main.map_or((0.0, f64::INFINITY), |m| (m, m));
+            let (min_cross, max_cross) = cross.map_or((0.0, max_cross), |c| (c, c));
+            Constraints {
+                min: axis.size(min_main, min_cross),
+                max: axis.size(max_main, max_cross),
+            }
+        };
+        let align_of = |tree: &Self, child: LayoutId| {
+            let style = &tree.nodes[child.idx()].layout.style;
+            match style.align_self.unwrap_or(flex.cross_align) {
+                Align::Stretch if has_fixed(style, axis.flip()) => Align::Start,
+                align => align,
+            }
+        };
+
+        // Measure every child at its basis.
+        let mut sizes = Vec::with_capacity(children.len());
+        for &child in &children {
+            let item = self.nodes[child.idx()].layout.style.flex;
+            let basis = item.basis.filter(|b| b.is_finite()).map(sanitize);
+            let size = self.layout(child, child_constraints(basis, None), measure);
+            sizes.push(size);
+        }
+
+        // Grow into leftover space or shrink to remove overflow.
+        let used: f64 = sizes.iter().map(|s| axis.main(*s)).sum::<f64>() + gaps;
+        let items: Vec<_> = children
+            .iter()
+            .map(|c| self.nodes[c.idx()].layout.style.flex)
+            .collect();
+        let total_grow: f64 = items.iter().map(|i| finite_or_zero(i.grow)).sum();
+        let target = if total_grow > 0.0 && max_main.is_finite() {
+            max_main
+        } else {
+            used.max(min_main).min(max_main)
+        };
+        let free = target - used;
+        let mut mains: Vec<f64> = sizes.iter().map(|s| axis.main(*s)).collect();
+        if free > 0.0 && total_grow > 0.0 {
+            for (main, item) in mains.iter_mut().zip(&items) {
+                *main += free * finite_or_zero(item.grow) / total_grow;
+            }
+        } else if free < 0.0 {
+            let total_weight: f64 = mains
+                .iter()
+                .zip(&items)
+                .map(|(main, item)| main * finite_or_zero(item.shrink))
+                .sum();
+            if total_weight > 0.0 {
+                for (main, item) in mains.iter_mut().zip(&items) {
+                    let weight = *main * finite_or_zero(item.shrink);
+                    *main = (*main + free * weight / total_weight).max(0.0);
+                }
+            }
+        }
+        for ((&child, size), &main) in children.iter().zip(&mut sizes).zip(&mains) {
+            if main != axis.main(*size) {
+                *size = self.layout(child, child_constraints(Some(main), None), measure);
+            }
+        }
+
+        // The cross size comes from the largest child; stretched children are sized to it.
+        let cross_size = sizes
+            .iter()
+            .map(|s| axis.cross(*s))
+            .fold(axis.cross(inner.min), f64::max)
+            .min(max_cross);
+        for (&child, size) in children.iter().zip(&mut sizes) {
+            if align_of(self, child) == Align::Stretch && axis.cross(*size) != cross_size {
+                let main = axis.main(*size);
+                *size = self.layout(
+                    child,
+                    child_constraints(Some(main), Some(cross_size)),
+                    measure,
+                );
+            }
+        }
+
+        let used: f64 = sizes.iter().map(|s| axis.main(*s)).sum::<f64>() + gaps;
+        let main_size = used.max(min_main).min(max_main);
+        let remaining = (main_size - used).max(0.0);
+        let (mut cursor, spacing) = match flex.justify {
+            Justify::Start => (0.0, gap),
+            Justify::Center => (remaining * 0.5, gap),
+            Justify::End => (remaining, gap),
+            Justify::SpaceBetween if children.len() > 1 => {
+                #[expect(
+                    clippy::cast_precision_loss,
+                    reason = "Child counts are far below 2^52."
+                )]
+                let between = remaining / (children.len() - 1) as f64;
+                (0.0, gap + between)
+            }
+            Justify::SpaceBetween => (0.0, gap),
+        };
+        for (&child, &size) in children.iter().zip(&sizes) {
+            let cross = align_of(self, child).offset(cross_size, axis.cross(size));
+            self.place(child, origin + axis.point(cursor, cross).to_vec2(), size);
+            cursor += axis.main(size) + spacing;
+        }
+        axis.size(main_size, cross_size)
+    }
+
+    fn layout_stack(
+        &mut self,
+        id: LayoutId,
+        stack: Stack,
+        inner: Constraints,
+        origin: Point,
+        measure: &mut Measure<'_>,
+    ) -> Size {
+        let children = self.nodes[id.idx()].children.clone();
+        let child_constraints = |width: Option<f64>, height: Option<f64>| {
+            let mut constraints = inner.loosen();
+            if let Some(width) = width {
+                constraints.min.width = width;
+                constraints.max.width = width;
+            }
+            if let Some(height) = height {
+                constraints.min.height = height;
+                constraints.max.height = height;
+            }
+            constraints
+        };
+        let mut sizes = Vec::with_capacity(children.len());
+        let mut size = inner.min;
+        for &child in &children {
+            let child_size = self.layout(child, inner.loosen(), measure);
+            size.width = size.width.max(child_size.width);
+            size.height = size.height.max(child_size.height);
+            sizes.push(child_size);
+        }
+        let size = inner.constrain(size);
+
+        for (&child, child_size) in children.iter().zip(&mut sizes) {
+            let style = &self.nodes[child.idx()].layout.style;
+            let stretch = |align: Align, axis: Axis| {
+                (align == Align::Stretch && !has_fixed(style, axis)).then_some(axis.main(size))
+            };
+            let width = stretch(stack.horizontal, Axis::Horizontal);
+            let height = stretch(stack.vertical, Axis::Vertical);
+            if width.is_some_and(|w| w != child_size.width)
+                || height.is_some_and(|h| h != child_size.height)
+            {
+                *child_size = self.layout(child, child_constraints(width, height), measure);
+            }
+            let offset = Vec2::new(
+                stack.horizontal.offset(size.width, child_size.width),
+                stack.vertical.offset(size.height, child_size.height),
+            );
+            self.place(child, origin + offset, *child_size);
+        }
+        size
+    }
+
+    fn layout_grid(
+        &mut self,
+        id: LayoutId,
+        grid: Grid,
+        inner: Constraints,
+        origin: Point,
+        measure: &mut Measure<'_>,
+    ) -> Size {
+        let children = self.nodes[id.idx()].children.clone();
+        let columns = grid.columns.max(1);
+        let column_gap = finite_or_zero(grid.column_gap);
+        let row_gap = finite_or_zero(grid.row_gap);
+        let row_height = grid.row_height.filter(|h| h.is_finite()).map(sanitize);
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "Column counts are far below 2^52."
+        )]
+        let columns_f = columns as f64;
+        let column_gaps = column_gap * (columns_f - 1.0);
+
+        let height_range = row_height.map_or((0.0, f64::INFINITY), |h| (h, h));
+        let cell = |width: Option<f64>| {
+            let (min_w, max_w) = width.map_or((0.0, f64::INFINITY), |w| (w, w));
+            Constraints {
+                min: Size::new(min_w, height_range.0),
+                max: Size::new(max_w, height_range.1),
+            }
+        };
+        let column_width = if inner.max.width.is_finite() {
+            ((inner.max.width - column_gaps) / columns_f).max(0.0)
+        } else {
+            let mut widest = ((inner.min.width - column_gaps) / columns_f).max(0.0);
+            for &child in &children {
+                widest = widest.max(self.layout(child, cell(None), measure).width);
+            }
+            widest
+        };
+
+        let mut y = 0.0;
+        let mut height = 0.0;
+        for row in children.chunks(columns) {
+            let mut sizes = Vec::with_capacity(row.len());
+            for &child in row {
+                sizes.push(self.layout(child, cell(Some(column_width)), measure));
+            }
+            let row_size =
+                row_height.unwrap_or_else(|| sizes.iter().map(|s| s.height).fold(0.0, f64::max));
+            let mut x = 0.0;
+            for (&child, &size) in row.iter().zip(&sizes) {
+                self.place(child, origin + Vec2::new(x, y), size);
+                x += column_width + column_gap;
+            }
+            height = y + row_size;
+            y = height + row_gap;
+        }
+        inner.constrain(Size::new(column_width * columns_f + column_gaps, height))
+    }
+}
+
+type Measure<'a> = dyn FnMut(LayoutId, Constraints) -> Size + 'a;
+
+/// Whether the style fixes the node's size along `axis`, which opts it out of stretching.
+fn has_fixed(style: &Style, axis: Axis) -> bool {
+    match axis {
+        Axis::Horizontal => style.width.is_some(),
+        Axis::Vertical => style.height.is_some(),
+    }
+}
+
+fn finite_or_zero(value: f64) -> f64 {
+    if value.is_finite() {
+        sanitize(value)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::FlexItem;
+
+    fn fixed(width: f64, height: f64) -> LayoutNode {
+        LayoutNode::leaf(Style::fixed(Size::new(width, height)))
+    }
+
+    #[test]
+    fn row_places_children_with_gap_and_stretches_cross_axis() {
+        let mut tree = LayoutTree::new();
+        let row = tree.insert(None, LayoutNode::container(Flex::row().with_gap(10.0)));
+        let a = tree.insert(
+            Some(row),
+            LayoutNode::leaf(Style {
+                width: Some(30.0),
+                ..Style::default()
+            }),
+        );
+        let b = tree.insert(Some(row), fixed(20.0, 40.0));
+        tree.compute(row, Size::new(f64::INFINITY, f64::INFINITY));
+
+        assert_eq!(tree.local_rect(row), Some(Rect::new(0.0, 0.0, 60.0, 40.0)));
+        // `a` has no height, so it stretches to the tallest sibling.
+        assert_eq!(tree.local_rect(a), Some(Rect::new(0.0, 0.0, 30.0, 40.0)));
+        assert_eq!(tree.local_rect(b), Some(Rect::new(40.0, 0.0, 60.0, 40.0)));
+    }
+
+    #[test]
+    fn flex_grow_shares_leftover_space() {
+        let mut tree = LayoutTree::new();
+        let row = tree.insert(None, LayoutNode::container(Flex::row()));
+        let fixed_child = tree.insert(Some(row), fixed(40.0, 10.0));
+        let mut grow = |grow| {
+            tree.insert(
+                Some(row),
+                LayoutNode::leaf(Style {
+                    flex: FlexItem::fill(grow),
+                    ..Style::default()
+                }),
+            )
+        };
+        let one = grow(1.0);
+        let three = grow(3.0);
+        tree.compute(row, Size::new(200.0, 50.0));
+
+        assert_eq!(tree.local_rect(row), Some(Rect::new(0.0, 0.0, 200.0, 50.0)));
+        // Fixed-size children are not stretched.
+        assert_eq!(
+            tree.local_rect(fixed_child),
+            Some(Rect::new(0.0, 0.0, 40.0, 10.0))
+        );
+        assert_eq!(tree.local_rect(one), Some(Rect::new(40.0, 0.0, 80.0, 50.0)));
+        assert_eq!(
+            tree.local_rect(three),
+            Some(Rect::new(80.0, 0.0, 200.0, 50.0))
+        );
+    }
+
+    #[test]
+    fn flex_shrink_removes_overflow_by_weight() {
+        let mut tree = LayoutTree::new();
+        let column = tree.insert(None, LayoutNode::container(Flex::column()));
+        let a = tree.insert(Some(column), fixed(10.0, 100.0));
+        let b = tree.insert(Some(column), fixed(10.0, 50.0));
+        let rigid = tree.insert(
+            Some(column),
+            LayoutNode::leaf(Style {
+                height: Some(50.0),
+                flex: FlexItem {
+                    shrink: 0.0,
+                    ..FlexItem::default()
+                },
+                ..Style::default()
+            }),
+        );
+        tree.compute(column, Size::new(10.0, 140.0));
+
+        // 60 units of overflow, shared 2:1 between `a` and `b` by their heights.
+        assert_eq!(tree.local_rect(a).unwrap().height(), 60.0);
+        assert_eq!(tree.local_rect(b).unwrap().height(), 30.0);
+        assert_eq!(
+            tree.local_rect(rigid),
+            Some(Rect::new(0.0, 90.0, 10.0, 140.0))
+        );
+    }
+
+    #[test]
+    fn justify_and_cross_align() {
+        let mut tree = LayoutTree::new();
+        let row = tree.insert(
+            None,
+            LayoutNode::container(
+                Flex::row()
+                    .with_justify(Justify::SpaceBetween)
+                    .with_cross_align(Align::Center),
+            )
+            .with_style(Style::fixed(Size::new(100.0, 30.0))),
+        );
+        let a = tree.insert(Some(row), fixed(10.0, 10.0));
+        let b = tree.insert(
+            Some(row),
+            LayoutNode::leaf(Style {
+                align_self: Some(Align::End),
+                ..Style::fixed(Size::new(10.0, 10.0))
+            }),
+        );
+        tree.compute(row, Size::new(f64::INFINITY, f64::INFINITY));
+
+        assert_eq!(tree.local_rect(a), Some(Rect::new(0.0, 10.0, 10.0, 20.0)));
+        assert_eq!(tree.local_rect(b), Some(Rect::new(90.0, 20.0, 100.0, 30.0)));
+    }
+
+    #[test]
+    fn stack_overlays_and_aligns_children() {
+        let mut tree = LayoutTree::new();
+        let stack = tree.insert(
+            None,
+            LayoutNode::container(Stack::new(Align::End, Align::Center)),
+        );
+        let base = tree.insert(Some(stack), fixed(100.0, 60.0));
+        let badge = tree.insert(Some(stack), fixed(20.0, 20.0));
+        tree.compute(stack, Size::new(f64::INFINITY, f64::INFINITY));
+
+        assert_eq!(
+            tree.local_rect(stack).unwrap().size(),
+            Size::new(100.0, 60.0)
+        );
+        assert_eq!(
+            tree.local_rect(base),
+            Some(Rect::new(0.0, 0.0, 100.0, 60.0))
+        );
+        assert_eq!(
+            tree.local_rect(badge),
+            Some(Rect::new(80.0, 20.0, 100.0, 40.0))
+        );
+    }
+
+    #[test]
+    fn grid_rows_take_tallest_cell() {
+        let mut tree = LayoutTree::new();
+        let grid = tree.insert(
+            None,
+            LayoutNode::container(Grid::new(2).with_gaps(10.0, 5.0)),
+        );
+        let cells: Vec<_> = [10.0, 30.0, 20.0]
+            .into_iter()
+            .map(|height| {
+                tree.insert(
+                    Some(grid),
+                    LayoutNode::leaf(Style {
+                        height: Some(height),
+                        ..Style::default()
+                    }),
+                )
+            })
+            .collect();
+        tree.compute(grid, Size::new(110.0, f64::INFINITY));
+
+        assert_eq!(
+            tree.local_rect(grid).unwrap().size(),
+            Size::new(110.0, 55.0)
+        );
+        assert_eq!(
+            tree.local_rect(cells[0]),
+            Some(Rect::new(0.0, 0.0, 50.0, 10.0))
+        );
+        assert_eq!(
+            tree.local_rect(cells[1]),
+            Some(Rect::new(60.0, 0.0, 110.0, 30.0))
+        );
+        assert_eq!(
+            tree.local_rect(cells[2]),
+            Some(Rect::new(0.0, 35.0, 50.0, 55.0))
+        );
+    }
+
+    #[test]
+    fn padding_offsets_children_and_absolute_rect_accumulates() {
+        let mut tree = LayoutTree::new();
+        let outer = tree.insert(
+            None,
+            LayoutNode::container(Flex::column()).with_style(Style {
+                padding: kurbo::Insets::uniform(8.0),
+                ..Style::default()
+            }),
+        );
+        let inner = tree.insert(
+            Some(outer),
+            LayoutNode::container(Flex::row()).with_style(Style {
+                padding: kurbo::Insets::new(4.0, 2.0, 0.0, 0.0),
+                ..Style::default()
+            }),
+        );
+        let leaf = tree.insert(Some(inner), fixed(10.0, 10.0));
+        tree.compute(outer, Size::new(100.0, 100.0));
+
+        assert_eq!(tree.local_rect(leaf), Some(Rect::new(4.0, 2.0, 14.0, 12.0)));
+        assert_eq!(
+            tree.absolute_rect(leaf),
+            Some(Rect::new(12.0, 10.0, 22.0, 20.0))
+        );
+        // The column stretches the row to its content width of 100 - 16.
+        assert_eq!(
+            tree.local_rect(inner),
+            Some(Rect::new(8.0, 8.0, 92.0, 20.0))
+        );
+    }
+
+    #[test]
+    fn measure_sizes_leaves() {
+        let mut tree = LayoutTree::new();
+        let column = tree.insert(None, LayoutNode::container(Flex::column()));
+        let label = tree.insert(Some(column), LayoutNode::default());
+        tree.compute_with(column, Size::new(50.0, 100.0), |id, constraints| {
+            assert_eq!(id, label);
+            // Wrap 1200 square units of text into the available width.
+            Size::new(constraints.max.width, 1200.0 / constraints.max.width)
+        });
+        assert_eq!(
+            tree.local_rect(label),
+            Some(Rect::new(0.0, 0.0, 50.0, 24.0))
+        );
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Fill-rule aware helpers for precise hit testing.
+//!
+//! The [`BezPath`] implementation of [`PreciseHitTest`] uses
+//! [`kurbo::Shape::contains`], which applies the non-zero winding rule. Paths
+//! that are painted with the even-odd rule (common in SVG and CAD imports)
+//! should use [`FilledPath`] so that holes hit test the same way they render.
+
+use kurbo::{BezPath, Point, Shape};
+
+use crate::{HitKind, HitParams, HitScore, PreciseHitTest};
+
+/// Rule deciding which regions of a self-overlapping path are filled.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum FillRule {
+    /// A point is inside if the path winds around it a non-zero number of times.
+    #[default]
+    NonZero,
+    /// A point is inside if the path winds around it an odd number of times.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// Returns whether a winding number counts as inside under this rule.
+    pub const fn is_inside(self, winding: i32) -> bool {
+        match self {
+            Self::NonZero => winding != 0,
+            Self::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// Returns whether `pt` is inside `path` under `fill_rule`.
+///
+/// The path is treated as closed, as for filling.
+pub fn path_contains(path: &BezPath, pt: Point, fill_rule: FillRule) -> bool {
+    fill_rule.is_inside(path.winding(pt))
+}
+
+/// A filled [`BezPath`] with an explicit [`FillRule`].
+///
+/// Hit testing works like the [`BezPath`] implementation, including the
+/// bounding-box based `fill_tolerance`, but uses the given fill rule.
+#[derive(Clone, Debug)]
+pub struct FilledPath {
+    /// The path outline in local coordinates.
+    pub path: BezPath,
+    /// The rule used to decide which regions are filled.
+    pub fill_rule: FillRule,
+}
+
+impl PreciseHitTest for FilledPath {
+    fn hit_test_local(&self, pt: Point, params: &HitParams) -> Option<HitScore> {
+        let bounds = self.path.bounding_box();
+        let inflated = if params.fill_tolerance > 0.0 {
+            bounds.inflate(params.fill_tolerance, params.fill_tolerance)
+        } else {
+            bounds
+        };
+        if !inflated.contains(pt) {
+            return None;
+        }
+        if path_contains(&self.path, pt, self.fill_rule) {
+            Some(HitScore::filled())
+        } else if params.fill_tolerance > 0.0 {
+            Some(HitScore {
+                distance: params.fill_tolerance,
+                kind: HitKind::Fill,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::Rect;
+
+    /// Two nested squares wound the same way: a hole only under even-odd.
+    fn nested_squares() -> BezPath {
+        let mut path = Rect::new(0.0, 0.0, 10.0, 10.0).to_path(0.1);
+        path.extend(Rect::new(3.0, 3.0, 7.0, 7.0).to_path(0.1));
+        path
+    }
+
+    #[test]
+    fn fill_rules_differ_in_overlap() {
+        let path = nested_squares();
+        let hole = Point::new(5.0, 5.0);
+        let ring = Point::new(1.0, 1.0);
+
+        assert!(path_contains(&path, hole, FillRule::NonZero));
+        assert!(!path_contains(&path, hole, FillRule::EvenOdd));
+        assert!(path_contains(&path, ring, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn filled_path_hit_respects_rule_and_tolerance() {
+        let even_odd = FilledPath {
+            path: nested_squares(),
+            fill_rule: FillRule::EvenOdd,
+        };
+        let params = HitParams::default();
+        assert!(
+            even_odd
+                .hit_test_local(Point::new(5.0, 5.0), &params)
+                .is_none()
+        );
+        assert!(
+            even_odd
+                .hit_test_local(Point::new(1.0, 1.0), &params)
+                .is_some()
+        );
+
+        let tolerant = HitParams {
+            fill_tolerance: 1.0,
+            ..HitParams::default()
+        };
+        let score = even_odd
+            .hit_test_local(Point::new(5.0, 5.0), &tolerant)
+            .expect("expected tolerant hit");
+        assert_eq!(score.distance, 1.0);
+    }
+}
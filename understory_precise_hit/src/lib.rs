@@ -40,11 +40,12 @@
 //! - [`Circle`] – circle, treated as a filled disk.
 //! - [`RoundedRect`] – rounded rectangle, treated as a filled shape; tolerant
 //!   hits are based on its bounding box.
-//! - [`BezPath`] – **fill-only** path hit using [`kurbo::Shape::contains`].
-//!   The fill rule is whatever `kurbo` uses for `contains`; it is not
-//!   currently configurable here. Stroke hits for paths are intentionally left
-//!   to higher-level engines, which can wrap their own stroke representations
-//!   and still implement [`PreciseHitTest`].
+//! - [`BezPath`] – **fill-only** path hit using [`kurbo::Shape::contains`],
+//!   which applies the non-zero winding rule. Use [`fill::FilledPath`] to hit
+//!   test a path with an explicit [`fill::FillRule`] such as even-odd.
+//!   Stroke hits for paths are intentionally left to higher-level engines,
+//!   which can wrap their own stroke representations and still implement
+//!   [`PreciseHitTest`].
 //!
 //! The [`stroke`] module provides helpers for stroke-oriented tests (for
 //! example, a simple [`stroke::StrokedLine`] type). These are minimal
@@ -61,6 +62,7 @@ use core::cmp::Ordering;
 use kurbo::common::FloatFuncs as _;
 use kurbo::{BezPath, Circle, Point, Rect, RoundedRect, Shape};
 
+pub mod fill;
 /// Stroke-oriented helpers and primitives.
 pub mod stroke;
 
@@ -24,7 +24,8 @@
 //! - No hashing or ordering constraints are imposed on `K`, making it easy to integrate
 //!   with existing ID types such as generational handles from a scene tree.
 //! - The API exposes simple operations that mirror common UI gestures like
-//!   “replace with a single item”, “toggle one item”, and “replace/extend with a batch”.
+//!   “replace with a single item”, “toggle one item”, “select from the anchor to here”,
+//!   and “replace/extend with a batch”.
 //!
 //! ## Minimal example
 //!
@@ -58,8 +59,13 @@
 //!   commands like “delete selection”.
 //! - **Anchor**: an optional reference key used as a starting point for range extension
 //!   (for example, shift-click in a list). The crate does not know how items are ordered;
-//!   callers are expected to compute ranges based on their own data structures and then
-//!   call methods like [`Selection::replace_with`] or [`Selection::extend_with`].
+//!   callers pass their display order to [`Selection::select_range`] and
+//!   [`Selection::extend_range`], or compute arbitrary sets (for example, from a marquee
+//!   hit-test query) and call [`Selection::replace_with`] or [`Selection::extend_with`].
+//!
+//! Every change bumps [`Selection::revision`]. Observers that need to know which items
+//! changed can keep a snapshot of [`Selection::items`] and compare it with
+//! [`Selection::added_since`] and [`Selection::removed_since`].
 //!
 //! The container is agnostic to the domain: it works equally well for list selections,
 //! canvas/infinite-surface editors, or any other place where you want to track a set of
@@ -98,28 +104,16 @@
 //!         return;
 //!     }
 //!
-//!     if mods.shift {
-//!         // Shift-click: treat anchor as the pivot, build a range between
-//!         // anchor and the clicked item according to the list ordering, and
-//!         // replace the current selection with that range.
-//!         let anchor = selection
-//!             .anchor()
-//!             .copied()
-//!             .unwrap_or(clicked);
-//!
-//!         let index_of = |value: u32| {
-//!             items_in_order
-//!                 .iter()
-//!                 .position(|&id| id == value)
-//!                 .expect("anchor and clicked must be in items_in_order")
-//!         };
-//!
-//!         let a = index_of(anchor);
-//!         let b = index_of(clicked);
-//!         let (start, end) = if a <= b { (a, b) } else { (b, a) };
+//!     if mods.ctrl && mods.shift {
+//!         // Ctrl+shift-click: add the anchor-to-clicked range to the selection.
+//!         selection.extend_range(items_in_order, clicked);
+//!         return;
+//!     }
 //!
-//!         let range = items_in_order[start..=end].iter().copied();
-//!         selection.replace_with(range);
+//!     if mods.shift {
+//!         // Shift-click: select from the anchor to the clicked item in list order,
+//!         // keeping the anchor as the pivot for the next shift-click.
+//!         selection.select_range(items_in_order, clicked);
 //!     }
 //! }
 //!
@@ -138,6 +132,18 @@
 //!     &items,
 //! );
 //! assert_eq!(sel.items(), &[20, 30, 40]);
+//!
+//! // Shift-click on 10: the range pivots around the anchor, 20.
+//! let before = sel.items().to_vec();
+//! handle_click(
+//!     &mut sel,
+//!     10,
+//!     Modifiers { ctrl: false, shift: true },
+//!     &items,
+//! );
+//! assert_eq!(sel.items(), &[10, 20]);
+//! assert_eq!(sel.added_since(&before).collect::<Vec<_>>(), [&10]);
+//! assert_eq!(sel.removed_since(&before).collect::<Vec<_>>(), [&30, &40]);
 //! ```
 //!
 //! This crate is `no_std` and uses `alloc`.
@@ -215,8 +221,7 @@ impl<T> Selection<T> {
     /// Returns a reference to the anchor key, if any.
     ///
     /// The anchor is often used as the starting point for range extension (for example,
-    /// shift-click in a list). The crate does not compute ranges; callers are expected
-    /// to derive them from their own data structures.
+    /// shift-click in a list); see [`Selection::select_range`].
     #[must_use]
     pub fn anchor(&self) -> Option<&T> {
         self.anchor.map(|idx| &self.items[idx])
@@ -391,6 +396,34 @@ where
         }
     }
 
+    /// Replaces the selection with the range between the anchor and `key`.
+    ///
+    /// `order` lists the keys in their display order (for example, the rows of a list).
+    /// All keys from the anchor to `key` inclusive are selected, the anchor is kept,
+    /// and `key` becomes primary, so repeated calls pivot around the same anchor.
+    ///
+    /// If there is no anchor, or the anchor or `key` is not in `order`, this behaves
+    /// like [`Selection::select_only`].
+    ///
+    /// This is the typical mapping for shift-click or shift+arrow keys.
+    pub fn select_range(&mut self, order: &[T], key: T)
+    where
+        T: Clone,
+    {
+        self.apply_range(order, key, false);
+    }
+
+    /// Adds the range between the anchor and `key` to the selection.
+    ///
+    /// Like [`Selection::select_range`], but keys that are already selected stay
+    /// selected. This is the typical mapping for ctrl+shift-click.
+    pub fn extend_range(&mut self, order: &[T], key: T)
+    where
+        T: Clone,
+    {
+        self.apply_range(order, key, true);
+    }
+
     /// Sets the primary key to `key` if it is already selected.
     pub fn set_primary(&mut self, key: &T) {
         if let Some(idx) = self.position_of(key)
@@ -419,11 +452,66 @@ where
         }
     }
 
+    /// Returns the keys in this selection that are not in `previous`.
+    ///
+    /// Together with [`Selection::removed_since`], this turns a snapshot of
+    /// [`Selection::items`] taken before a change into per-item notifications,
+    /// for example to repaint only the items whose selected state changed.
+    pub fn added_since<'a>(&'a self, previous: &'a [T]) -> impl Iterator<Item = &'a T> + 'a {
+        self.items.iter().filter(move |key| !previous.contains(key))
+    }
+
+    /// Returns the keys in `previous` that are no longer in this selection.
+    ///
+    /// See [`Selection::added_since`].
+    pub fn removed_since<'a>(&'a self, previous: &'a [T]) -> impl Iterator<Item = &'a T> + 'a {
+        previous.iter().filter(move |key| !self.items.contains(key))
+    }
+
     /// Returns the position of `key` within the selection, if present.
     fn position_of(&self, key: &T) -> Option<usize> {
         self.items.iter().position(|k| k == key)
     }
 
+    /// Replaces (or extends) the selection with the range of `order` between the
+    /// anchor and `key`, leaving the anchor in place and making `key` primary.
+    fn apply_range(&mut self, order: &[T], key: T, extend: bool)
+    where
+        T: Clone,
+    {
+        let anchor_pos = self
+            .anchor()
+            .and_then(|anchor| order.iter().position(|k| k == anchor));
+        let key_pos = order.iter().position(|k| k == &key);
+        let (Some(a), Some(b)) = (anchor_pos, key_pos) else {
+            self.select_only(key);
+            return;
+        };
+        let (start, end) = if a <= b { (a, b) } else { (b, a) };
+
+        let mut new_items = if extend {
+            self.items.clone()
+        } else {
+            Vec::new()
+        };
+        for k in &order[start..=end] {
+            if !new_items.contains(k) {
+                new_items.push(k.clone());
+            }
+        }
+        let new_anchor = new_items.iter().position(|k| k == &order[a]);
+        let new_primary = new_items.iter().position(|k| k == &order[b]);
+
+        if new_items == self.items && self.primary == new_primary && self.anchor == new_anchor {
+            return;
+        }
+
+        self.items = new_items;
+        self.primary = new_primary;
+        self.anchor = new_anchor;
+        self.bump_revision();
+    }
+
     /// Removes the item at `idx`, updating primary and anchor accordingly.
     fn remove_at(&mut self, idx: usize) {
         self.items.remove(idx);
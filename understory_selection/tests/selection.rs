@@ -159,3 +159,65 @@ fn clear_anchor_only_changes_when_anchor_is_some() {
     sel.clear_anchor();
     assert_eq!(sel.revision(), rev_without_anchor);
 }
+
+#[test]
+fn select_range_pivots_around_anchor() {
+    let order = [10, 20, 30, 40, 50];
+    let mut sel = Selection::new();
+    sel.select_only(30);
+
+    sel.select_range(&order, 50);
+    assert_eq!(sel.items(), &[30, 40, 50]);
+    assert_eq!(sel.anchor(), Some(&30));
+    assert_eq!(sel.primary(), Some(&50));
+
+    // A second range replaces the first but keeps the same anchor.
+    sel.select_range(&order, 10);
+    assert_eq!(sel.items(), &[10, 20, 30]);
+    assert_eq!(sel.anchor(), Some(&30));
+    assert_eq!(sel.primary(), Some(&10));
+
+    // Repeating the same range is a no-op.
+    let rev = sel.revision();
+    sel.select_range(&order, 10);
+    assert_eq!(sel.revision(), rev);
+}
+
+#[test]
+fn select_range_without_usable_anchor_selects_only_key() {
+    let order = [1, 2, 3];
+    let mut sel = Selection::new();
+    sel.select_range(&order, 2);
+    assert_eq!(sel.items(), &[2]);
+    assert_eq!(sel.anchor(), Some(&2));
+
+    // Anchor not in the ordering.
+    sel.select_only(99);
+    sel.select_range(&order, 3);
+    assert_eq!(sel.items(), &[3]);
+}
+
+#[test]
+fn extend_range_keeps_existing_items() {
+    let order = [1, 2, 3, 4, 5];
+    let mut sel = Selection::new();
+    sel.select_only(5);
+    sel.toggle(2);
+    sel.set_anchor(&2);
+
+    sel.extend_range(&order, 3);
+    assert_eq!(sel.items(), &[5, 2, 3]);
+    assert_eq!(sel.anchor(), Some(&2));
+    assert_eq!(sel.primary(), Some(&3));
+}
+
+#[test]
+fn added_and_removed_since_snapshot() {
+    let mut sel = Selection::new();
+    sel.replace_with([1, 2, 3]);
+    let before = sel.items().to_vec();
+
+    sel.replace_with([2, 3, 4]);
+    assert_eq!(sel.added_since(&before).collect::<Vec<_>>(), [&4]);
+    assert_eq!(sel.removed_since(&before).collect::<Vec<_>>(), [&1]);
+}
@@ -0,0 +1,545 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`TextEditor`] state machine.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::movement::{
+    clamp_offset, line_end, line_start, next_grapheme, next_word, prev_grapheme, prev_word,
+};
+
+/// A selection in the text, as byte offsets.
+///
+/// The anchor is where the selection started and stays put while extending; the focus is
+/// where the caret is drawn. A collapsed selection (anchor equals focus) is a plain caret.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TextSelection {
+    /// The fixed end of the selection.
+    pub anchor: usize,
+    /// The moving end of the selection, where the caret is.
+    pub focus: usize,
+}
+
+impl TextSelection {
+    /// Creates a selection from `anchor` to `focus`.
+    #[must_use]
+    pub const fn new(anchor: usize, focus: usize) -> Self {
+        Self { anchor, focus }
+    }
+
+    /// Creates a collapsed selection (a caret) at `offset`.
+    #[must_use]
+    pub const fn caret(offset: usize) -> Self {
+        Self::new(offset, offset)
+    }
+
+    /// Returns `true` if the selection is a caret.
+    #[must_use]
+    pub const fn is_collapsed(&self) -> bool {
+        self.anchor == self.focus
+    }
+
+    /// Returns the selected byte range, in ascending order.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.anchor.min(self.focus)..self.anchor.max(self.focus)
+    }
+}
+
+/// Caret movements, as produced by arrow keys and their modifiers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Movement {
+    /// One grapheme cluster towards the start of the text.
+    Left,
+    /// One grapheme cluster towards the end of the text.
+    Right,
+    /// To the start of the previous word.
+    WordLeft,
+    /// To the end of the next word.
+    WordRight,
+    /// To the start of the current line.
+    LineStart,
+    /// To the end of the current line.
+    LineEnd,
+    /// To the start of the text.
+    DocumentStart,
+    /// To the end of the text.
+    DocumentEnd,
+}
+
+/// A single replacement applied to the text.
+///
+/// Every mutation of a [`TextEditor`] is described by an `Edit`, which callers can use to
+/// update layout incrementally. The editor keeps them on its undo and redo stacks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Edit {
+    /// The byte range that was replaced, in the text before the edit.
+    pub range: Range<usize>,
+    /// The text that was removed from `range`.
+    pub deleted: String,
+    /// The text that was inserted at `range.start`.
+    pub inserted: String,
+    /// The selection before the edit.
+    pub selection_before: TextSelection,
+    /// The selection after the edit.
+    pub selection_after: TextSelection,
+}
+
+impl Edit {
+    /// Returns the byte range of the inserted text, in the text after the edit.
+    #[must_use]
+    pub fn inserted_range(&self) -> Range<usize> {
+        self.range.start..self.range.start + self.inserted.len()
+    }
+
+    /// Returns the edit that reverts this one.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self {
+            range: self.inserted_range(),
+            deleted: self.inserted.clone(),
+            inserted: self.deleted.clone(),
+            selection_before: self.selection_after,
+            selection_after: self.selection_before,
+        }
+    }
+}
+
+/// In-progress IME composition text.
+///
+/// Preedit text is shown inline at [`Preedit::offset`] but is not part of the editor's text
+/// until the input method commits it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Preedit {
+    /// Byte offset in the text where the composition is shown.
+    pub offset: usize,
+    /// The composition text.
+    pub text: String,
+    /// The input method's caret or highlighted range, as byte offsets into `text`.
+    pub cursor: Option<Range<usize>>,
+}
+
+/// A non-visual text editing core.
+///
+/// `TextEditor` owns a string, a [`TextSelection`], undo/redo history, and IME composition
+/// state. Hosts translate keyboard, pointer, and IME events into calls on the editor and use
+/// the returned [`Edit`]s and the [`TextEditor::revision`] counter to update layout and
+/// rendering.
+///
+/// Consecutive typed insertions are merged into a single undo step until the caret is moved,
+/// whitespace follows a word, or [`TextEditor::break_undo_group`] is called.
+#[derive(Clone, Debug, Default)]
+pub struct TextEditor {
+    text: String,
+    selection: TextSelection,
+    preedit: Option<Preedit>,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+    coalesce: bool,
+    revision: u64,
+}
+
+impl TextEditor {
+    /// Creates an editor holding `text`, with the caret at the end.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let end = text.len();
+        Self {
+            text,
+            selection: TextSelection::caret(end),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the text, without any composition in progress.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the current selection.
+    #[must_use]
+    pub fn selection(&self) -> TextSelection {
+        self.selection
+    }
+
+    /// Returns the selected text.
+    #[must_use]
+    pub fn selected_text(&self) -> &str {
+        &self.text[self.selection.range()]
+    }
+
+    /// Returns a counter that changes whenever the text changes.
+    #[must_use]
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Sets the selection, clamping both ends to character boundaries in the text.
+    pub fn set_selection(&mut self, selection: TextSelection) {
+        self.selection = TextSelection::new(
+            clamp_offset(&self.text, selection.anchor),
+            clamp_offset(&self.text, selection.focus),
+        );
+        self.coalesce = false;
+    }
+
+    /// Selects the whole text.
+    pub fn select_all(&mut self) {
+        self.set_selection(TextSelection::new(0, self.text.len()));
+    }
+
+    /// Moves the caret, extending the selection from its anchor if `extend` is set.
+    ///
+    /// Without `extend`, [`Movement::Left`] and [`Movement::Right`] collapse a non-empty
+    /// selection to its start or end instead of moving past it.
+    pub fn move_caret(&mut self, movement: Movement, extend: bool) {
+        let focus = self.selection.focus;
+        if !extend && !self.selection.is_collapsed() {
+            let range = self.selection.range();
+            match movement {
+                Movement::Left => return self.set_selection(TextSelection::caret(range.start)),
+                Movement::Right => return self.set_selection(TextSelection::caret(range.end)),
+                _ => {}
+            }
+        }
+        let text = self.text.as_str();
+        let target = match movement {
+            Movement::Left => prev_grapheme(text, focus),
+            Movement::Right => next_grapheme(text, focus),
+            Movement::WordLeft => prev_word(text, focus),
+            Movement::WordRight => next_word(text, focus),
+            Movement::LineStart => line_start(text, focus),
+            Movement::LineEnd => line_end(text, focus),
+            Movement::DocumentStart => 0,
+            Movement::DocumentEnd => text.len(),
+        };
+        let anchor = if extend {
+            self.selection.anchor
+        } else {
+            target
+        };
+        self.set_selection(TextSelection::new(anchor, target));
+    }
+
+    /// Inserts `text` in place of the selection, as when typing or pasting.
+    ///
+    /// Returns the applied edit, or `None` if nothing changed.
+    pub fn insert(&mut self, text: &str) -> Option<Edit> {
+        self.preedit = None;
+        let range = self.selection.range();
+        let coalesce = self.coalesce && range.is_empty();
+        let edit = self.replace_range(range, text)?;
+        if coalesce {
+            self.merge_last_insertion();
+        }
+        // Typing keeps coalescing, but whitespace after a word starts a new undo step.
+        self.coalesce = !text.is_empty() && !text.ends_with(char::is_whitespace);
+        Some(edit)
+    }
+
+    /// Deletes the selection, or the grapheme cluster before the caret.
+    pub fn delete_backward(&mut self) -> Option<Edit> {
+        self.delete_to(prev_grapheme)
+    }
+
+    /// Deletes the selection, or the grapheme cluster after the caret.
+    pub fn delete_forward(&mut self) -> Option<Edit> {
+        self.delete_to(next_grapheme)
+    }
+
+    /// Deletes the selection, or back to the start of the previous word.
+    pub fn delete_word_backward(&mut self) -> Option<Edit> {
+        self.delete_to(prev_word)
+    }
+
+    /// Deletes the selection, or forward to the end of the next word.
+    pub fn delete_word_forward(&mut self) -> Option<Edit> {
+        self.delete_to(next_word)
+    }
+
+    fn delete_to(&mut self, target: impl FnOnce(&str, usize) -> usize) -> Option<Edit> {
+        self.preedit = None;
+        let range = if self.selection.is_collapsed() {
+            let focus = self.selection.focus;
+            let other = target(&self.text, focus);
+            focus.min(other)..focus.max(other)
+        } else {
+            self.selection.range()
+        };
+        self.coalesce = false;
+        self.replace_range(range, "")
+    }
+
+    /// Replaces the byte `range` of the text with `text` and places the caret after it.
+    ///
+    /// The range is clamped to character boundaries. This records an undo step, clears the
+    /// redo history, and ends any IME composition. Returns the applied edit, or `None` if
+    /// nothing changed.
+    pub fn replace_range(&mut self, range: Range<usize>, text: &str) -> Option<Edit> {
+        let start = clamp_offset(&self.text, range.start);
+        let end = clamp_offset(&self.text, range.end).max(start);
+        if start == end && text.is_empty() {
+            return None;
+        }
+        let edit = Edit {
+            range: start..end,
+            deleted: String::from(&self.text[start..end]),
+            inserted: String::from(text),
+            selection_before: self.selection,
+            selection_after: TextSelection::caret(start + text.len()),
+        };
+        self.apply(&edit);
+        self.undo.push(edit.clone());
+        self.redo.clear();
+        self.coalesce = false;
+        Some(edit)
+    }
+
+    /// Merges the two most recent undo steps if the newer one continues the older insertion.
+    fn merge_last_insertion(&mut self) {
+        let [.., previous, last] = self.undo.as_slice() else {
+            return;
+        };
+        if !previous.deleted.is_empty()
+            || !last.deleted.is_empty()
+            || previous.inserted_range().end != last.range.start
+        {
+            return;
+        }
+        let last = self.undo.pop().expect("checked above");
+        let previous = self.undo.last_mut().expect("checked above");
+        previous.inserted.push_str(&last.inserted);
+        previous.selection_after = last.selection_after;
+    }
+
+    /// Ends the current undo group, so the next insertion becomes its own undo step.
+    pub fn break_undo_group(&mut self) {
+        self.coalesce = false;
+    }
+
+    /// Returns `true` if there is an edit to undo.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an edit to redo.
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Reverts the most recent edit and returns the change applied to the text.
+    pub fn undo(&mut self) -> Option<Edit> {
+        let edit = self.undo.pop()?;
+        let inverse = edit.inverse();
+        self.apply(&inverse);
+        self.redo.push(edit);
+        self.coalesce = false;
+        Some(inverse)
+    }
+
+    /// Reapplies the most recently undone edit and returns it.
+    pub fn redo(&mut self) -> Option<Edit> {
+        let edit = self.redo.pop()?;
+        self.apply(&edit);
+        self.undo.push(edit.clone());
+        self.coalesce = false;
+        Some(edit)
+    }
+
+    /// Applies `edit` to the text. Any composition ends, since its offset may no longer exist.
+    fn apply(&mut self, edit: &Edit) {
+        self.preedit = None;
+        self.text.replace_range(edit.range.clone(), &edit.inserted);
+        self.selection = edit.selection_after;
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Returns the IME composition in progress, if any.
+    #[must_use]
+    pub fn preedit(&self) -> Option<&Preedit> {
+        self.preedit.as_ref()
+    }
+
+    /// Updates the IME composition text shown at the caret.
+    ///
+    /// When a composition starts over a non-empty selection, the selection is deleted first
+    /// (as an undoable edit). An empty `text` ends the composition without inserting anything.
+    /// `cursor` is a byte range within `text`; invalid ranges are dropped.
+    pub fn set_preedit(&mut self, text: &str, cursor: Option<Range<usize>>) {
+        if text.is_empty() {
+            self.preedit = None;
+            return;
+        }
+        if self.preedit.is_none() && !self.selection.is_collapsed() {
+            self.replace_range(self.selection.range(), "");
+        }
+        let cursor = cursor.filter(|c| {
+            c.start <= c.end
+                && c.end <= text.len()
+                && text.is_char_boundary(c.start)
+                && text.is_char_boundary(c.end)
+        });
+        self.preedit = Some(Preedit {
+            offset: self.selection.focus,
+            text: String::from(text),
+            cursor,
+        });
+    }
+
+    /// Ends the IME composition and inserts the committed `text`.
+    pub fn commit_preedit(&mut self, text: &str) -> Option<Edit> {
+        self.preedit = None;
+        self.break_undo_group();
+        let edit = self.insert(text);
+        self.break_undo_group();
+        edit
+    }
+
+    /// Returns the text as displayed, with any composition spliced in at its offset.
+    #[must_use]
+    pub fn display_text(&self) -> String {
+        let mut display = self.text.clone();
+        if let Some(preedit) = &self.preedit {
+            display.insert_str(clamp_offset(&self.text, preedit.offset), &preedit.text);
+        }
+        display
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_replaces_selection_and_coalesces() {
+        let mut ed = TextEditor::new("hello world");
+        ed.set_selection(TextSelection::new(6, 11));
+        ed.insert("t");
+        ed.insert("h");
+        ed.insert("ere");
+        assert_eq!(ed.text(), "hello there");
+        assert_eq!(ed.selection(), TextSelection::caret(11));
+
+        // The first keystroke replaced the selection; the rest merged into one more step.
+        let undone = ed.undo().unwrap();
+        assert_eq!(undone.deleted, "here");
+        assert_eq!(ed.text(), "hello t");
+        ed.undo();
+        assert_eq!(ed.text(), "hello world");
+        assert_eq!(ed.selection(), TextSelection::new(6, 11));
+        assert!(!ed.can_undo());
+
+        ed.redo();
+        ed.redo();
+        assert_eq!(ed.text(), "hello there");
+        assert!(!ed.can_redo());
+    }
+
+    #[test]
+    fn whitespace_and_caret_moves_break_undo_groups() {
+        let mut ed = TextEditor::new("");
+        ed.insert("a");
+        ed.insert(" ");
+        ed.insert("b");
+        ed.move_caret(Movement::Left, false);
+        ed.move_caret(Movement::Right, false);
+        ed.insert("c");
+        ed.undo();
+        assert_eq!(ed.text(), "a b");
+        ed.undo();
+        assert_eq!(ed.text(), "a ");
+        ed.undo();
+        assert_eq!(ed.text(), "");
+    }
+
+    #[test]
+    fn delete_by_grapheme_and_word() {
+        let mut ed = TextEditor::new("cafe\u{301} au lait");
+        ed.move_caret(Movement::DocumentStart, false);
+        ed.move_caret(Movement::WordRight, false);
+        ed.delete_backward();
+        assert_eq!(ed.text(), "caf au lait");
+        ed.delete_word_forward();
+        assert_eq!(ed.text(), "caf lait");
+        ed.move_caret(Movement::DocumentEnd, false);
+        ed.delete_word_backward();
+        assert_eq!(ed.text(), "caf ");
+        assert_eq!(ed.delete_forward(), None);
+    }
+
+    #[test]
+    fn movement_extends_and_collapses() {
+        let mut ed = TextEditor::new("one two\nthree");
+        ed.move_caret(Movement::DocumentStart, false);
+        ed.move_caret(Movement::WordRight, true);
+        ed.move_caret(Movement::WordRight, true);
+        assert_eq!(ed.selected_text(), "one two");
+        ed.move_caret(Movement::Left, false);
+        assert_eq!(ed.selection(), TextSelection::caret(0));
+        ed.set_selection(TextSelection::new(9, 9));
+        ed.move_caret(Movement::LineStart, true);
+        assert_eq!(ed.selection(), TextSelection::new(9, 8));
+        ed.move_caret(Movement::LineEnd, false);
+        assert_eq!(ed.selection(), TextSelection::caret(13));
+    }
+
+    #[test]
+    fn preedit_is_shown_but_not_committed_until_commit() {
+        let mut ed = TextEditor::new("ab");
+        ed.set_selection(TextSelection::caret(1));
+        ed.set_preedit("に", Some(0..3));
+        assert_eq!(ed.text(), "ab");
+        assert_eq!(ed.display_text(), "aにb");
+        ed.set_preedit("にほ", None);
+        assert_eq!(ed.preedit().unwrap().offset, 1);
+
+        ed.commit_preedit("日本");
+        assert!(ed.preedit().is_none());
+        assert_eq!(ed.text(), "a日本b");
+        ed.undo();
+        assert_eq!(ed.text(), "ab");
+    }
+
+    #[test]
+    fn preedit_over_selection_deletes_it() {
+        let mut ed = TextEditor::new("abc");
+        ed.select_all();
+        ed.set_preedit("x", Some(0..9));
+        assert_eq!(ed.text(), "");
+        assert_eq!(ed.preedit().unwrap().cursor, None);
+        ed.set_preedit("", None);
+        assert!(ed.preedit().is_none());
+        assert_eq!(ed.display_text(), "");
+    }
+
+    // Replacing text directly ends the composition instead of leaving a stale offset.
+    #[test]
+    fn replace_range_ends_preedit() {
+        let mut ed = TextEditor::new("abc");
+        ed.set_selection(TextSelection::caret(3));
+        ed.set_preedit("x", None);
+        assert_eq!(ed.display_text(), "abcx");
+        ed.replace_range(0..3, "");
+        assert!(ed.preedit().is_none());
+        assert_eq!(ed.display_text(), "");
+    }
+
+    #[test]
+    fn replace_range_clamps_and_tracks_revision() {
+        let mut ed = TextEditor::new("héllo");
+        let rev = ed.revision();
+        let edit = ed.replace_range(2..99, "ey").unwrap();
+        assert_eq!(edit.range, 1..6);
+        assert_eq!(edit.deleted, "éllo");
+        assert_eq!(ed.text(), "hey");
+        assert_ne!(ed.revision(), rev);
+        assert_eq!(ed.replace_range(1..1, ""), None);
+    }
+}
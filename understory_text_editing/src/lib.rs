@@ -0,0 +1,63 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// After you edit the crate's doc comment, run this command, then check README.md for any missing links
+// cargo rdme --workspace-project=understory_text_editing --heading-base-level=0
+
+//! Understory Text Editing: a non-visual text editing core.
+//!
+//! This crate owns the state behind an editable text field that does not depend on how the
+//! text is laid out or drawn:
+//!
+//! - [`TextEditor`]: the text, a [`TextSelection`], undo/redo history, and IME composition.
+//! - [`Movement`]: grapheme, word, line, and document caret movements.
+//! - [`Edit`]: a description of every change, for incremental layout and undo.
+//! - [`Preedit`]: in-progress IME composition text shown inline at the caret.
+//! - [`movement`]: the boundary functions behind caret movement, usable on any `&str`.
+//!
+//! Offsets are byte offsets into UTF-8 text and are always kept on character boundaries.
+//! Caret movement and deletion step over whole grapheme clusters, so combining marks and
+//! emoji sequences are never split.
+//!
+//! It does **not** own text layout, shaping, hit testing of glyphs, visual (soft-wrapped)
+//! line movement, clipboard access, or rendering. Hosts map pointer positions to offsets with
+//! their layout engine, map key and IME events to editor calls, and redraw when
+//! [`TextEditor::revision`] changes.
+//!
+//! The text is stored in a single `String`, which suits the single- and multi-line fields
+//! of typical UIs; large documents would want a rope behind the same API.
+//!
+//! ## Minimal example
+//!
+//! ```rust
+//! use understory_text_editing::{Movement, TextEditor};
+//!
+//! let mut editor = TextEditor::new("Hello world");
+//!
+//! // Ctrl+shift+left selects the last word; typing replaces it.
+//! editor.move_caret(Movement::WordLeft, true);
+//! assert_eq!(editor.selected_text(), "world");
+//! editor.insert("there");
+//! assert_eq!(editor.text(), "Hello there");
+//!
+//! // IME composition is shown inline but only committed at the end.
+//! editor.insert(", ");
+//! editor.set_preedit("にほん", None);
+//! assert_eq!(editor.display_text(), "Hello there, にほん");
+//! editor.commit_preedit("日本");
+//! assert_eq!(editor.text(), "Hello there, 日本");
+//!
+//! editor.undo();
+//! assert_eq!(editor.text(), "Hello there, ");
+//! ```
+//!
+//! This crate is `no_std` and uses `alloc`.
+
+#![no_std]
+
+extern crate alloc;
+
+mod editor;
+pub mod movement;
+
+pub use editor::{Edit, Movement, Preedit, TextEditor, TextSelection};
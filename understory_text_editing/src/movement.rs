@@ -0,0 +1,154 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Grapheme, word, and line boundaries in UTF-8 text.
+//!
+//! All offsets are byte offsets into the text. Functions accept any offset and clamp it to the
+//! text length; offsets that are not on a character boundary are snapped back to the previous
+//! one.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Clamps `offset` to the text and snaps it back to the nearest character boundary.
+#[must_use]
+pub fn clamp_offset(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+/// Returns the start of the grapheme cluster before `offset`, or `0` at the start.
+#[must_use]
+pub fn prev_grapheme(text: &str, offset: usize) -> usize {
+    let offset = clamp_offset(text, offset);
+    text[..offset]
+        .grapheme_indices(true)
+        .next_back()
+        .map_or(0, |(start, _)| start)
+}
+
+/// Returns the end of the grapheme cluster after `offset`, or the text length at the end.
+#[must_use]
+pub fn next_grapheme(text: &str, offset: usize) -> usize {
+    let offset = clamp_offset(text, offset);
+    text[offset..]
+        .graphemes(true)
+        .next()
+        .map_or(text.len(), |g| offset + g.len())
+}
+
+/// Returns `true` if a word segment is a word rather than whitespace or punctuation.
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(char::is_alphanumeric)
+}
+
+/// Returns the start of the word at or before `offset`, skipping whitespace and punctuation.
+///
+/// This is the target of "move one word left" (for example, ctrl+left on Windows and Linux).
+#[must_use]
+pub fn prev_word(text: &str, offset: usize) -> usize {
+    let offset = clamp_offset(text, offset);
+    text[..offset]
+        .split_word_bound_indices()
+        .rev()
+        .find(|(_, segment)| is_word(segment))
+        .map_or(0, |(start, _)| start)
+}
+
+/// Returns the end of the word at or after `offset`, skipping whitespace and punctuation.
+///
+/// This is the target of "move one word right".
+#[must_use]
+pub fn next_word(text: &str, offset: usize) -> usize {
+    let offset = clamp_offset(text, offset);
+    text[offset..]
+        .split_word_bound_indices()
+        .find(|(_, segment)| is_word(segment))
+        .map_or(text.len(), |(start, segment)| {
+            offset + start + segment.len()
+        })
+}
+
+/// Returns the range of the word containing `offset`, for double-click selection.
+///
+/// If `offset` is in whitespace or punctuation, the range of that segment is returned instead.
+#[must_use]
+pub fn word_range(text: &str, offset: usize) -> core::ops::Range<usize> {
+    let offset = clamp_offset(text, offset);
+    text.split_word_bound_indices()
+        .find(|(start, segment)| offset < start + segment.len())
+        .map_or(offset..offset, |(start, segment)| {
+            start..start + segment.len()
+        })
+}
+
+/// Returns the start of the line containing `offset`.
+///
+/// Lines are separated by `\n`; soft-wrapped visual lines are a layout concern.
+#[must_use]
+pub fn line_start(text: &str, offset: usize) -> usize {
+    let offset = clamp_offset(text, offset);
+    text[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
+
+/// Returns the end of the line containing `offset`, before its `\n` (and any `\r`).
+#[must_use]
+pub fn line_end(text: &str, offset: usize) -> usize {
+    let offset = clamp_offset(text, offset);
+    let end = text[offset..].find('\n').map_or(text.len(), |i| offset + i);
+    if end > offset && text[..end].ends_with('\r') {
+        end - 1
+    } else {
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graphemes_keep_clusters_together() {
+        // "e" + combining acute, then a family emoji made of several code points.
+        let text = "ae\u{301}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}b";
+        let after_e = 1 + "e\u{301}".len();
+        assert_eq!(next_grapheme(text, 1), after_e);
+        assert_eq!(prev_grapheme(text, after_e), 1);
+        let emoji_end = text.len() - 1;
+        assert_eq!(next_grapheme(text, after_e), emoji_end);
+        assert_eq!(prev_grapheme(text, emoji_end), after_e);
+        assert_eq!(prev_grapheme(text, 0), 0);
+        assert_eq!(next_grapheme(text, text.len()), text.len());
+    }
+
+    #[test]
+    fn offsets_snap_to_char_boundaries() {
+        let text = "é!";
+        assert_eq!(clamp_offset(text, 1), 0);
+        assert_eq!(clamp_offset(text, 99), text.len());
+    }
+
+    #[test]
+    fn words_skip_whitespace_and_punctuation() {
+        let text = "hello, brave  world";
+        assert_eq!(next_word(text, 0), 5);
+        assert_eq!(next_word(text, 5), 12);
+        assert_eq!(next_word(text, 12), text.len());
+        assert_eq!(prev_word(text, text.len()), 14);
+        assert_eq!(prev_word(text, 14), 7);
+        assert_eq!(prev_word(text, 3), 0);
+        assert_eq!(word_range(text, 8), 7..12);
+    }
+
+    #[test]
+    fn lines_split_on_newlines() {
+        let text = "one\r\ntwo\nthree";
+        assert_eq!(line_start(text, 7), 5);
+        assert_eq!(line_end(text, 0), 3);
+        assert_eq!(line_end(text, 5), 8);
+        assert_eq!(line_start(text, 9), 9);
+        assert_eq!(line_end(text, 9), text.len());
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ops::Range;
+
+use kurbo::{Point, Rect, Vec2};
+
+use crate::validation::{point_is_finite, view_rect_is_valid, view_span_is_valid};
+
+/// Edge auto-scroll for drags near the border of a view.
+///
+/// While a drag is active (drag-to-select, drag-and-drop), call [`EdgeAutoScroll::pan_delta`]
+/// on every animation tick. When the pointer is within [`EdgeAutoScroll::margin`] of an edge of
+/// the view, or past it, the returned delta scrolls towards that edge at a speed proportional to
+/// how deep the pointer is in the margin, up to [`EdgeAutoScroll::max_speed`].
+///
+/// The delta is in view/device units and is meant to be passed straight to
+/// [`Viewport2D::pan_by_view`](crate::Viewport2D::pan_by_view) (or
+/// [`Viewport1D::pan_by_view`](crate::Viewport1D::pan_by_view) for
+/// [`EdgeAutoScroll::pan_delta_1d`]).
+///
+/// ```rust
+/// use kurbo::{Point, Rect, Vec2};
+/// use understory_view2d::{EdgeAutoScroll, Viewport2D};
+///
+/// let mut view = Viewport2D::new(Rect::new(0.0, 0.0, 800.0, 600.0));
+/// let auto_scroll = EdgeAutoScroll::new(40.0, 1000.0);
+///
+/// // Pointer halfway into the right margin: half speed, revealing content to the right.
+/// let delta = auto_scroll.pan_delta(view.view_rect(), Point::new(780.0, 300.0), 16);
+/// assert_eq!(delta, Vec2::new(-8.0, 0.0));
+/// view.pan_by_view(delta);
+/// ```
+#[derive(Clone, Debug)]
+pub struct EdgeAutoScroll {
+    /// Width of the band along each edge that triggers scrolling, in view/device units.
+    pub margin: f64,
+    /// Scroll speed when the pointer is at or past the edge, in view/device units per second.
+    pub max_speed: f64,
+}
+
+impl Default for EdgeAutoScroll {
+    /// A 32-unit margin and a maximum speed of 1200 units per second.
+    fn default() -> Self {
+        Self::new(32.0, 1200.0)
+    }
+}
+
+impl EdgeAutoScroll {
+    /// Creates an edge auto-scroll helper.
+    #[must_use]
+    pub fn new(margin: f64, max_speed: f64) -> Self {
+        Self { margin, max_speed }
+    }
+
+    /// Returns the pan delta for one tick of `dt_ms` milliseconds.
+    ///
+    /// `view_rect` is the view's rectangle and `pointer` the pointer position, both in
+    /// view/device units. Returns [`Vec2::ZERO`] when the pointer is away from all edges or any
+    /// input is invalid (non-finite values, a non-positive margin or speed).
+    #[must_use]
+    pub fn pan_delta(&self, view_rect: Rect, pointer: Point, dt_ms: u64) -> Vec2 {
+        if !view_rect_is_valid(view_rect) || !point_is_finite(pointer) {
+            return Vec2::ZERO;
+        }
+        Vec2::new(
+            self.axis_delta(view_rect.x0..view_rect.x1, pointer.x, dt_ms),
+            self.axis_delta(view_rect.y0..view_rect.y1, pointer.y, dt_ms),
+        )
+    }
+
+    /// Returns the pan delta along a single axis for one tick of `dt_ms` milliseconds.
+    ///
+    /// See [`EdgeAutoScroll::pan_delta`].
+    #[must_use]
+    pub fn pan_delta_1d(&self, view_span: Range<f64>, pointer: f64, dt_ms: u64) -> f64 {
+        if !view_span_is_valid(&view_span) || !pointer.is_finite() {
+            return 0.0;
+        }
+        self.axis_delta(view_span, pointer, dt_ms)
+    }
+
+    fn axis_delta(&self, span: Range<f64>, pointer: f64, dt_ms: u64) -> f64 {
+        let margin_valid = self.margin.is_finite() && self.margin > 0.0;
+        let speed_valid = self.max_speed.is_finite() && self.max_speed > 0.0;
+        if !margin_valid || !speed_valid {
+            return 0.0;
+        }
+        // Margins overlap on spans narrower than two margins; use half the span instead.
+        let margin = self.margin.min((span.end - span.start) * 0.5);
+        if margin <= 0.0 {
+            return 0.0;
+        }
+        let before = ((span.start + margin - pointer) / margin).clamp(0.0, 1.0);
+        let after = ((pointer - (span.end - margin)) / margin).clamp(0.0, 1.0);
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "Tick durations are far below f64's integer precision."
+        )]
+        let dt = dt_ms as f64 / 1000.0;
+        // Near the start edge, content moves towards the end to reveal what is before it.
+        (before - after) * self.max_speed * dt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEW: Rect = Rect::new(0.0, 0.0, 200.0, 100.0);
+
+    #[test]
+    fn no_scroll_away_from_edges() {
+        let a = EdgeAutoScroll::new(20.0, 1000.0);
+        assert_eq!(a.pan_delta(VIEW, Point::new(100.0, 50.0), 16), Vec2::ZERO);
+        assert_eq!(a.pan_delta(VIEW, Point::new(20.0, 20.0), 16), Vec2::ZERO);
+    }
+
+    #[test]
+    fn speed_is_proportional_to_penetration() {
+        let a = EdgeAutoScroll::new(20.0, 1000.0);
+        assert_eq!(
+            a.pan_delta(VIEW, Point::new(10.0, 50.0), 100),
+            Vec2::new(50.0, 0.0)
+        );
+        assert_eq!(
+            a.pan_delta(VIEW, Point::new(100.0, 95.0), 100),
+            Vec2::new(0.0, -75.0)
+        );
+        // Past the edge scrolls at full speed, diagonally in a corner.
+        assert_eq!(
+            a.pan_delta(VIEW, Point::new(250.0, -30.0), 100),
+            Vec2::new(-100.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn narrow_views_and_invalid_inputs() {
+        let a = EdgeAutoScroll::new(20.0, 1000.0);
+        // Margins shrink to half of a 20-unit span, so the center is neutral.
+        assert_eq!(a.pan_delta_1d(0.0..20.0, 10.0, 100), 0.0);
+        assert_eq!(a.pan_delta_1d(0.0..20.0, 0.0, 100), 100.0);
+        assert_eq!(a.pan_delta_1d(0.0..0.0, 0.0, 100), 0.0);
+        assert_eq!(a.pan_delta_1d(0.0..20.0, f64::NAN, 100), 0.0);
+        assert_eq!(
+            a.pan_delta(VIEW, Point::new(f64::INFINITY, 0.0), 16),
+            Vec2::ZERO
+        );
+        assert_eq!(
+            EdgeAutoScroll::new(-1.0, 1000.0).pan_delta_1d(0.0..100.0, 0.0, 100),
+            0.0
+        );
+    }
+}
@@ -12,6 +12,15 @@
 //! - Coordinate conversion between world and view/device (pixel) space.
 //! - View fitting and centering/alignment helpers.
 //! - Simple zoom / pan constraints with finite-state input hardening.
+//! - Headless scrollbar geometry ([`Scrollbar`]) derived from viewport state, with hit
+//!   regions, thumb dragging, and auto-hide timing.
+//! - Edge auto-scroll deltas ([`EdgeAutoScroll`]) for drags near the view border.
+//! - Stepped zoom levels ([`ZoomLadder`]) and snapping to exact scale ([`ZoomSnap`]).
+//! - Zoom-based level-of-detail selection with hysteresis ([`LodPolicy`]).
+//! - Rubber-band overscroll past the world bounds ([`RubberBand`]), with the displacement
+//!   reported for edge effects and eased back after release.
+//! - Logical ↔ device pixel conversion for [`Viewport2D`], kept consistent across DPI changes
+//!   with [`Viewport2D::set_scale_factor`].
 //!
 //! It does **not** own any scene graph, input event model, rendering backend,
 //! or physical-unit policy. Callers are expected to:
@@ -122,11 +131,23 @@
 
 #![no_std]
 
+mod autoscroll;
+mod lod;
 mod modes;
+mod overscroll;
+mod scrollbar;
 mod validation;
 mod viewport1d;
 mod viewport2d;
+mod zoom;
 
+pub use autoscroll::EdgeAutoScroll;
+pub use lod::LodPolicy;
 pub use modes::{ClampMode, FitMode};
+pub use overscroll::RubberBand;
+pub use scrollbar::{
+    ScrollAxis, Scrollbar, ScrollbarAutoHide, ScrollbarDrag, ScrollbarLayout, ScrollbarPart,
+};
 pub use viewport1d::{Viewport1D, Viewport1DDebugInfo};
 pub use viewport2d::{Viewport2D, Viewport2DDebugInfo};
+pub use zoom::{ZoomLadder, ZoomSnap};
@@ -0,0 +1,185 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::validation::sanitize_zoom_value;
+
+/// Picks a level of detail from the viewport zoom, with hysteresis at level boundaries.
+///
+/// A policy is a list of ascending zoom thresholds: below the first threshold content is at
+/// level 0, between the first and second at level 1, and so on. Levels usually index content
+/// variants from coarsest to finest, such as pre-recorded pictures at increasing detail. A
+/// single threshold expresses "hide below zoom X": level 0 is hidden and level 1 is shown.
+///
+/// Selecting purely by threshold flickers when zoom hovers around a boundary, for example
+/// during a slow pinch. [`LodPolicy::update`] takes the level currently shown and only moves
+/// to another level once zoom is past the boundary by the relative `hysteresis` margin.
+///
+/// Sharing one policy between the scene layer and application code keeps them in agreement
+/// about what is drawn at a given zoom.
+///
+/// Thresholds are expected in ascending order. Non-finite and non-positive thresholds are
+/// skipped.
+///
+/// Thresholds are logical zoom levels. With a [`Viewport2D`](crate::Viewport2D), pass
+/// [`Viewport2D::logical_zoom`] rather than the device zoom, so the chosen level does not shift
+/// with the display's scale factor.
+///
+/// [`Viewport2D::logical_zoom`]: crate::Viewport2D::logical_zoom
+///
+/// ```rust
+/// use understory_view2d::LodPolicy;
+///
+/// // Outline below 50%, simplified up to 200%, full detail above.
+/// let policy = LodPolicy::new(&[0.5, 2.0]);
+/// assert_eq!(policy.level(1.0), 1);
+///
+/// // Zooming in just past 2.0 keeps the simplified variant until the margin is cleared.
+/// let mut level = policy.level(1.0);
+/// level = policy.update(2.05, level);
+/// assert_eq!(level, 1);
+/// level = policy.update(2.5, level);
+/// assert_eq!(level, 2);
+///
+/// // And zooming back out just below 2.0 keeps full detail.
+/// level = policy.update(1.95, level);
+/// assert_eq!(level, 2);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LodPolicy<'a> {
+    thresholds: &'a [f64],
+    hysteresis: f64,
+}
+
+impl<'a> LodPolicy<'a> {
+    /// The default relative hysteresis margin, 10%.
+    pub const DEFAULT_HYSTERESIS: f64 = 0.1;
+
+    /// Creates a policy over ascending zoom `thresholds` with the default hysteresis.
+    #[must_use]
+    pub const fn new(thresholds: &'a [f64]) -> Self {
+        Self {
+            thresholds,
+            hysteresis: Self::DEFAULT_HYSTERESIS,
+        }
+    }
+
+    /// Returns the policy with a different relative hysteresis margin.
+    ///
+    /// For example `0.1` requires zoom to be 10% past a threshold before switching level.
+    /// Non-finite or negative values are ignored.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: f64) -> Self {
+        if hysteresis.is_finite() && hysteresis >= 0.0 {
+            self.hysteresis = hysteresis;
+        }
+        self
+    }
+
+    /// Returns the zoom thresholds.
+    #[must_use]
+    pub fn thresholds(&self) -> &'a [f64] {
+        self.thresholds
+    }
+
+    /// Returns the relative hysteresis margin.
+    #[must_use]
+    pub fn hysteresis(&self) -> f64 {
+        self.hysteresis
+    }
+
+    fn valid_thresholds(&self) -> impl Iterator<Item = f64> + 'a {
+        self.thresholds
+            .iter()
+            .copied()
+            .filter(|threshold| sanitize_zoom_value(*threshold).is_some())
+    }
+
+    /// Returns the number of levels, one more than the number of valid thresholds.
+    #[must_use]
+    pub fn level_count(&self) -> usize {
+        self.valid_thresholds().count() + 1
+    }
+
+    /// Returns the level for `zoom` without hysteresis, for content shown for the first time.
+    ///
+    /// Invalid zoom values select level 0.
+    #[must_use]
+    pub fn level(&self, zoom: f64) -> usize {
+        if sanitize_zoom_value(zoom).is_none() {
+            return 0;
+        }
+        self.valid_thresholds()
+            .take_while(|threshold| zoom >= *threshold)
+            .count()
+    }
+
+    /// Returns the level for `zoom` given the level `current` shown until now.
+    ///
+    /// Moves up a level only once `zoom` exceeds the threshold by the hysteresis margin, and
+    /// down only once it falls below the threshold by the same margin. Invalid zoom values keep
+    /// the current level.
+    #[must_use]
+    pub fn update(&self, zoom: f64, current: usize) -> usize {
+        let mut level = current.min(self.level_count() - 1);
+        if sanitize_zoom_value(zoom).is_none() {
+            return level;
+        }
+        let margin = 1.0 + self.hysteresis;
+        while let Some(threshold) = self.valid_thresholds().nth(level)
+            && zoom >= threshold * margin
+        {
+            level += 1;
+        }
+        while level > 0
+            && let Some(threshold) = self.valid_thresholds().nth(level - 1)
+            && zoom < threshold / margin
+        {
+            level -= 1;
+        }
+        level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_counts_thresholds_below_zoom() {
+        let policy = LodPolicy::new(&[0.5, 2.0]);
+        assert_eq!(policy.level_count(), 3);
+        assert_eq!(policy.level(0.25), 0);
+        assert_eq!(policy.level(0.5), 1);
+        assert_eq!(policy.level(1.9), 1);
+        assert_eq!(policy.level(2.0), 2);
+        assert_eq!(policy.level(f64::NAN), 0);
+    }
+
+    #[test]
+    fn update_applies_hysteresis_both_ways() {
+        let policy = LodPolicy::new(&[1.0]).with_hysteresis(0.2);
+        assert_eq!(policy.update(1.1, 0), 0);
+        assert_eq!(policy.update(1.2, 0), 1);
+        assert_eq!(policy.update(0.9, 1), 1);
+        assert_eq!(policy.update(0.8, 1), 0);
+        assert_eq!(policy.update(f64::INFINITY, 1), 1);
+    }
+
+    #[test]
+    fn update_can_cross_several_levels() {
+        let policy = LodPolicy::new(&[0.5, 1.0, 2.0]);
+        assert_eq!(policy.update(8.0, 0), 3);
+        assert_eq!(policy.update(0.1, 3), 0);
+        // Out-of-range current levels are clamped first.
+        assert_eq!(policy.update(1.5, 9), 2);
+    }
+
+    #[test]
+    fn invalid_thresholds_and_hysteresis_are_ignored() {
+        let policy = LodPolicy::new(&[f64::NAN, 0.0, 1.0]).with_hysteresis(-1.0);
+        assert_eq!(policy.hysteresis(), LodPolicy::DEFAULT_HYSTERESIS);
+        assert_eq!(policy.level_count(), 2);
+        assert_eq!(policy.level(1.0), 1);
+        assert_eq!(LodPolicy::new(&[]).update(5.0, 0), 0);
+    }
+}
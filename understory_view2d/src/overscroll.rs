@@ -0,0 +1,197 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::modes::FitMode;
+
+/// Resistance coefficient of the rubber-band curve; lower values feel stiffer.
+const RESISTANCE: f64 = 0.55;
+
+/// Overscrolls smaller than this many view units snap back to rest when settling.
+const SETTLE_EPSILON: f64 = 0.5;
+
+/// Rubber-band response for panning past the world bounds.
+///
+/// Used with [`Viewport2D::pan_by_view_elastic`](crate::Viewport2D::pan_by_view_elastic) and
+/// [`Viewport1D::pan_by_view_elastic`](crate::Viewport1D::pan_by_view_elastic): once the view
+/// reaches the edge of the world bounds, further panning moves the content by a diminishing
+/// amount that approaches [`RubberBand::limit`]. When the gesture ends, call
+/// `settle_overscroll` on every animation tick until it returns `false` to ease back to the
+/// bounds.
+///
+/// The resting position is the one that keeps the view inside the world bounds; when the
+/// bounds are smaller than the view along an axis, it follows the viewport's [`FitMode`].
+///
+/// ```rust
+/// use kurbo::{Rect, Vec2};
+/// use understory_view2d::{RubberBand, Viewport2D};
+///
+/// let mut view = Viewport2D::new(Rect::new(0.0, 0.0, 800.0, 600.0));
+/// view.set_world_bounds(Some(Rect::new(0.0, 0.0, 2000.0, 2000.0)));
+/// let rubber = RubberBand::default();
+///
+/// // Dragging the content 100 units right at its left edge only moves it part of the way.
+/// view.pan_by_view_elastic(Vec2::new(100.0, 0.0), &rubber);
+/// let overscroll = view.overscroll();
+/// assert!(overscroll.x > 0.0 && overscroll.x < 100.0);
+///
+/// // After release, it eases back.
+/// while view.settle_overscroll(&rubber, 16) {}
+/// assert_eq!(view.overscroll(), Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RubberBand {
+    /// The largest displacement past the bounds, in view/device units.
+    ///
+    /// A non-positive or non-finite limit disables overscroll.
+    pub limit: f64,
+    /// Time constant of the settle animation in milliseconds; larger values settle slower.
+    ///
+    /// A non-positive or non-finite value settles immediately.
+    pub settle_ms: f64,
+}
+
+impl Default for RubberBand {
+    /// A 120-unit limit with a 100 ms settle time constant.
+    fn default() -> Self {
+        Self {
+            limit: 120.0,
+            settle_ms: 100.0,
+        }
+    }
+}
+
+impl RubberBand {
+    fn effective_limit(&self) -> Option<f64> {
+        (self.limit.is_finite() && self.limit > 0.0).then_some(self.limit)
+    }
+
+    /// Maps an unconstrained overscroll distance to the displayed displacement.
+    ///
+    /// The result has the same sign as `overscroll`, is close to it for small distances, and
+    /// approaches `limit` for large ones.
+    #[must_use]
+    pub fn resist(&self, overscroll: f64) -> f64 {
+        let Some(limit) = self.effective_limit() else {
+            return 0.0;
+        };
+        if !overscroll.is_finite() {
+            return 0.0;
+        }
+        let magnitude = limit * (1.0 - 1.0 / (overscroll.abs() * RESISTANCE / limit + 1.0));
+        magnitude.copysign(overscroll)
+    }
+
+    /// Inverse of [`RubberBand::resist`]: the unconstrained distance behind a displacement.
+    fn unresist(&self, displayed: f64) -> f64 {
+        let Some(limit) = self.effective_limit() else {
+            return 0.0;
+        };
+        let magnitude = displayed.abs().min(limit * 0.999);
+        (limit / RESISTANCE * magnitude / (limit - magnitude)).copysign(displayed)
+    }
+
+    /// Returns the displacement after settling for `dt_ms` milliseconds.
+    pub(crate) fn settle(&self, displayed: f64, dt_ms: u64) -> f64 {
+        if !(self.settle_ms.is_finite() && self.settle_ms > 0.0) {
+            return 0.0;
+        }
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "Frame intervals are far below 2^52 milliseconds."
+        )]
+        let next = displayed / (1.0 + dt_ms as f64 / self.settle_ms);
+        if next.abs() < SETTLE_EPSILON {
+            0.0
+        } else {
+            next
+        }
+    }
+
+    /// Returns the pan after applying `delta` to `pan` with rubber-band resistance.
+    ///
+    /// `rest` maps any pan to its resting pan inside the bounds.
+    pub(crate) fn pan_axis(&self, pan: f64, delta: f64, rest: impl Fn(f64) -> f64) -> f64 {
+        let current_rest = rest(pan);
+        let unconstrained = current_rest + self.unresist(pan - current_rest) + delta;
+        let new_rest = rest(unconstrained);
+        new_rest + self.resist(unconstrained - new_rest)
+    }
+}
+
+/// Returns the pan along one axis that keeps the view inside the world bounds, closest to `pan`.
+///
+/// Pan follows the viewport convention `view = view_start + pan + zoom * world`. When the bounds
+/// are shorter than the view, the resting pan is determined by `fit_mode` alone.
+pub(crate) fn resting_pan(
+    pan: f64,
+    view_len: f64,
+    bounds_min: f64,
+    bounds_max: f64,
+    zoom: f64,
+    fit_mode: FitMode,
+) -> f64 {
+    let max_pan = -zoom * bounds_min;
+    let min_pan = view_len - zoom * bounds_max;
+    if min_pan > max_pan {
+        match fit_mode {
+            FitMode::Center => (min_pan + max_pan) * 0.5,
+            FitMode::AlignMin => max_pan,
+        }
+    } else {
+        pan.clamp(min_pan, max_pan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resist_is_monotonic_and_bounded() {
+        let rubber = RubberBand::default();
+        assert_eq!(rubber.resist(0.0), 0.0);
+        let small = rubber.resist(10.0);
+        let large = rubber.resist(10_000.0);
+        assert!(small > 0.0 && small < 10.0);
+        assert!(large > small && large < rubber.limit);
+        assert_eq!(rubber.resist(-10.0), -small);
+        assert!((rubber.unresist(small) - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn disabled_rubber_band_hard_clamps() {
+        let rubber = RubberBand {
+            limit: 0.0,
+            settle_ms: f64::NAN,
+        };
+        assert_eq!(rubber.resist(50.0), 0.0);
+        assert_eq!(rubber.settle(30.0, 16), 0.0);
+        assert_eq!(rubber.pan_axis(0.0, 50.0, |p| p.min(0.0)), 0.0);
+    }
+
+    #[test]
+    fn resting_pan_respects_fit_mode_for_small_bounds() {
+        // Bounds 0..100 at zoom 1 in a 200-unit view.
+        assert_eq!(
+            resting_pan(37.0, 200.0, 0.0, 100.0, 1.0, FitMode::Center),
+            50.0
+        );
+        assert_eq!(
+            resting_pan(37.0, 200.0, 0.0, 100.0, 1.0, FitMode::AlignMin),
+            0.0
+        );
+        // Bounds 0..1000: pan may range over -800..0.
+        assert_eq!(
+            resting_pan(20.0, 200.0, 0.0, 1000.0, 1.0, FitMode::Center),
+            0.0
+        );
+        assert_eq!(
+            resting_pan(-900.0, 200.0, 0.0, 1000.0, 1.0, FitMode::Center),
+            -800.0
+        );
+        assert_eq!(
+            resting_pan(-300.0, 200.0, 0.0, 1000.0, 1.0, FitMode::Center),
+            -300.0
+        );
+    }
+}
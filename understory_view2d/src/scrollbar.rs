@@ -0,0 +1,518 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ops::Range;
+
+use kurbo::Rect;
+
+use crate::validation::{view_span_is_valid, world_range_is_valid};
+use crate::{Viewport1D, Viewport2D};
+
+/// Axis of a [`Viewport2D`] that a [`Scrollbar`] tracks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScrollAxis {
+    /// The X axis (a horizontal scrollbar).
+    Horizontal,
+    /// The Y axis (a vertical scrollbar).
+    Vertical,
+}
+
+/// Headless scrollbar geometry for one axis.
+///
+/// A `Scrollbar` owns only its track span, expressed in view/device units along the
+/// scrollbar's axis, and a minimum thumb length. Given the scrollable content range and the
+/// currently visible range (both in world units), [`Scrollbar::layout`] computes where the
+/// thumb sits on the track. Rendering and input handling are left to the caller.
+#[derive(Clone, Debug)]
+pub struct Scrollbar {
+    track: Range<f64>,
+    min_thumb_len: f64,
+}
+
+/// Thumb and track placement computed by [`Scrollbar::layout`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScrollbarLayout {
+    /// The track span in view/device units.
+    pub track: Range<f64>,
+    /// The thumb span in view/device units, always within `track`.
+    pub thumb: Range<f64>,
+    /// Scroll position as a fraction in `[0, 1]`: `0` at the start of the content, `1` at the end.
+    pub position: f64,
+    /// The scrollable extent in world units: the union of the content and the visible range.
+    pub extent: Range<f64>,
+    /// The visible range in world units.
+    pub visible: Range<f64>,
+}
+
+/// Part of a scrollbar under the pointer, as returned by [`ScrollbarLayout::part_at`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScrollbarPart {
+    /// The track before the thumb; clicking it pages towards the start.
+    TrackBefore,
+    /// The thumb; pressing it starts a [`ScrollbarDrag`].
+    Thumb,
+    /// The track after the thumb; clicking it pages towards the end.
+    TrackAfter,
+}
+
+impl Scrollbar {
+    /// Creates a scrollbar with the given track span in view/device units.
+    ///
+    /// The minimum thumb length defaults to `16.0`. Non-finite or reversed spans are treated
+    /// as an empty `0.0..0.0` track.
+    #[must_use]
+    pub fn new(track: Range<f64>) -> Self {
+        let track = if view_span_is_valid(&track) {
+            track
+        } else {
+            0.0..0.0
+        };
+        Self {
+            track,
+            min_thumb_len: 16.0,
+        }
+    }
+
+    /// Returns the track span in view/device units.
+    #[must_use]
+    pub fn track(&self) -> Range<f64> {
+        self.track.clone()
+    }
+
+    /// Updates the track span, for example after the scroll area is resized.
+    ///
+    /// Non-finite or reversed spans are ignored.
+    pub fn set_track(&mut self, track: Range<f64>) {
+        if view_span_is_valid(&track) {
+            self.track = track;
+        }
+    }
+
+    /// Returns the minimum thumb length in view/device units.
+    #[must_use]
+    pub fn min_thumb_len(&self) -> f64 {
+        self.min_thumb_len
+    }
+
+    /// Sets the minimum thumb length in view/device units.
+    ///
+    /// Keeps very long content grabbable. Negative or non-finite lengths are ignored.
+    pub fn set_min_thumb_len(&mut self, len: f64) {
+        if len.is_finite() && len >= 0.0 {
+            self.min_thumb_len = len;
+        }
+    }
+
+    /// Computes the thumb placement for `visible` within `content`, both in world units.
+    ///
+    /// The thumb length is proportional to the visible fraction of the content, but never
+    /// shorter than the minimum thumb length (or longer than the track). If the visible range
+    /// extends past the content (for example with [`ClampMode::KeepSomeVisible`]), the
+    /// scrollable extent grows to include it, so the thumb shrinks rather than leaving the track.
+    ///
+    /// Returns `None` when there is nothing to scroll: the visible range covers the whole
+    /// extent, or either range is non-finite or empty.
+    ///
+    /// [`ClampMode::KeepSomeVisible`]: crate::ClampMode::KeepSomeVisible
+    #[must_use]
+    pub fn layout(&self, content: Range<f64>, visible: Range<f64>) -> Option<ScrollbarLayout> {
+        if !world_range_is_valid(&content) || !world_range_is_valid(&visible) {
+            return None;
+        }
+        let extent = content.start.min(visible.start)..content.end.max(visible.end);
+        let extent_len = extent.end - extent.start;
+        let visible_len = visible.end - visible.start;
+        let scroll_len = extent_len - visible_len;
+        if scroll_len <= 0.0 {
+            return None;
+        }
+
+        let track_len = self.track.end - self.track.start;
+        let thumb_len = (track_len * visible_len / extent_len)
+            .max(self.min_thumb_len)
+            .min(track_len);
+        let position = ((visible.start - extent.start) / scroll_len).clamp(0.0, 1.0);
+        let thumb_start = self.track.start + position * (track_len - thumb_len);
+
+        Some(ScrollbarLayout {
+            track: self.track.clone(),
+            thumb: thumb_start..thumb_start + thumb_len,
+            position,
+            extent,
+            visible,
+        })
+    }
+
+    /// Computes the thumb placement for a [`Viewport1D`].
+    ///
+    /// Uses the viewport's world bounds as the content range. Returns `None` if the viewport
+    /// has no world bounds or nothing to scroll.
+    #[must_use]
+    pub fn layout_1d(&self, view: &Viewport1D) -> Option<ScrollbarLayout> {
+        self.layout(view.world_bounds()?, view.visible_world_range())
+    }
+
+    /// Computes the thumb placement for one axis of a [`Viewport2D`].
+    ///
+    /// Uses the viewport's world bounds as the content rectangle. Returns `None` if the
+    /// viewport has no world bounds or nothing to scroll along `axis`.
+    #[must_use]
+    pub fn layout_2d(&self, view: &Viewport2D, axis: ScrollAxis) -> Option<ScrollbarLayout> {
+        let bounds = view.world_bounds()?;
+        let visible = view.visible_world_rect();
+        match axis {
+            ScrollAxis::Horizontal => self.layout(bounds.x0..bounds.x1, visible.x0..visible.x1),
+            ScrollAxis::Vertical => self.layout(bounds.y0..bounds.y1, visible.y0..visible.y1),
+        }
+    }
+}
+
+impl ScrollbarLayout {
+    /// Returns the part of the scrollbar at `pos`, a view/device coordinate along the track.
+    ///
+    /// Returns `None` outside the track. The thumb wins at its edges.
+    #[must_use]
+    pub fn part_at(&self, pos: f64) -> Option<ScrollbarPart> {
+        if !(self.track.start..=self.track.end).contains(&pos) {
+            return None;
+        }
+        Some(if pos < self.thumb.start {
+            ScrollbarPart::TrackBefore
+        } else if pos > self.thumb.end {
+            ScrollbarPart::TrackAfter
+        } else {
+            ScrollbarPart::Thumb
+        })
+    }
+
+    /// Returns how many world units the content scrolls per view unit of thumb movement.
+    ///
+    /// Returns `0.0` if the thumb fills the track and cannot move.
+    #[must_use]
+    pub fn world_per_thumb_unit(&self) -> f64 {
+        let travel = (self.track.end - self.track.start) - (self.thumb.end - self.thumb.start);
+        let scroll_len =
+            (self.extent.end - self.extent.start) - (self.visible.end - self.visible.start);
+        if travel > 0.0 {
+            scroll_len / travel
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the world-space scroll delta for clicking `part`: one visible length towards the
+    /// click for track parts, and `0.0` for the thumb.
+    ///
+    /// Apply it to a viewport with `pan_by_view(-delta * zoom)`.
+    #[must_use]
+    pub fn page_delta(&self, part: ScrollbarPart) -> f64 {
+        let page = self.visible.end - self.visible.start;
+        match part {
+            ScrollbarPart::TrackBefore => -page,
+            ScrollbarPart::Thumb => 0.0,
+            ScrollbarPart::TrackAfter => page,
+        }
+    }
+
+    /// Returns the track as a rectangle, given the span across the scrollbar (its thickness).
+    ///
+    /// `cross` is in view/device units: a Y span for [`ScrollAxis::Horizontal`] and an X span
+    /// for [`ScrollAxis::Vertical`]. Use the result for hit regions or for drawing, for
+    /// example as a [`RoundedRect`](kurbo::RoundedRect) with the app's thumb radius.
+    #[must_use]
+    pub fn track_rect(&self, axis: ScrollAxis, cross: Range<f64>) -> Rect {
+        axis_rect(axis, &self.track, &cross)
+    }
+
+    /// Returns the thumb as a rectangle, given the span across the scrollbar.
+    ///
+    /// See [`ScrollbarLayout::track_rect`].
+    #[must_use]
+    pub fn thumb_rect(&self, axis: ScrollAxis, cross: Range<f64>) -> Rect {
+        axis_rect(axis, &self.thumb, &cross)
+    }
+}
+
+fn axis_rect(axis: ScrollAxis, along: &Range<f64>, cross: &Range<f64>) -> Rect {
+    match axis {
+        ScrollAxis::Horizontal => Rect::new(along.start, cross.start, along.end, cross.end),
+        ScrollAxis::Vertical => Rect::new(cross.start, along.start, cross.end, along.end),
+    }
+}
+
+/// An in-progress thumb drag.
+///
+/// Maps pointer movement along the track to a proportional scroll position, so the thumb stays
+/// under the pointer where it was grabbed.
+///
+/// ```rust
+/// use understory_view2d::{Scrollbar, ScrollbarDrag, Viewport1D};
+///
+/// let mut view = Viewport1D::new(0.0..100.0);
+/// view.set_world_bounds(Some(0.0..400.0));
+/// let bar = Scrollbar::new(0.0..100.0);
+///
+/// // Grab the thumb (0..25) in the middle and drag it by 10 pixels.
+/// let layout = bar.layout_1d(&view).unwrap();
+/// let drag = ScrollbarDrag::begin(&layout, 12.0).unwrap();
+/// let delta = drag.world_start(22.0) - view.visible_world_range().start;
+/// view.pan_by_view(-delta * view.zoom());
+///
+/// assert_eq!(view.visible_world_range(), 40.0..140.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScrollbarDrag {
+    grab: f64,
+    start_world: f64,
+    world_per_unit: f64,
+}
+
+impl ScrollbarDrag {
+    /// Starts a drag if `pointer` (along the track) is on the thumb.
+    #[must_use]
+    pub fn begin(layout: &ScrollbarLayout, pointer: f64) -> Option<Self> {
+        if !pointer.is_finite() || layout.part_at(pointer) != Some(ScrollbarPart::Thumb) {
+            return None;
+        }
+        Some(Self {
+            grab: pointer,
+            start_world: layout.visible.start,
+            world_per_unit: layout.world_per_thumb_unit(),
+        })
+    }
+
+    /// Returns the world-space start of the visible range for the current `pointer` position.
+    ///
+    /// Non-finite pointer positions leave the scroll position where the drag started.
+    #[must_use]
+    pub fn world_start(&self, pointer: f64) -> f64 {
+        if !pointer.is_finite() {
+            return self.start_world;
+        }
+        self.start_world + (pointer - self.grab) * self.world_per_unit
+    }
+}
+
+/// Auto-hide timing for overlay scrollbars.
+///
+/// Scrollbars are shown on scroll activity, stay fully opaque while hovered or dragged, and
+/// fade out after a period of inactivity. Times are in milliseconds from any monotonic clock.
+#[derive(Clone, Debug)]
+pub struct ScrollbarAutoHide {
+    /// How long the scrollbar stays fully visible after the last activity.
+    pub hide_delay: u64,
+    /// How long the fade-out takes once the hide delay has passed.
+    pub fade_duration: u64,
+    last_activity: Option<u64>,
+    held: bool,
+}
+
+impl Default for ScrollbarAutoHide {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScrollbarAutoHide {
+    /// Creates a hidden scrollbar timer with a 1000ms hide delay and a 200ms fade.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hide_delay: 1000,
+            fade_duration: 200,
+            last_activity: None,
+            held: false,
+        }
+    }
+
+    /// Shows the scrollbar and restarts the hide delay, for example on scrolling.
+    pub fn on_activity(&mut self, now: u64) {
+        self.last_activity = Some(now);
+    }
+
+    /// Keeps the scrollbar visible while `held` (hovered or dragged).
+    ///
+    /// Releasing restarts the hide delay from `now`.
+    pub fn set_held(&mut self, held: bool, now: u64) {
+        if self.held && !held {
+            self.last_activity = Some(now);
+        }
+        self.held = held;
+    }
+
+    /// Returns the scrollbar opacity in `[0, 1]` at `now`.
+    #[must_use]
+    pub fn opacity(&self, now: u64) -> f64 {
+        if self.held {
+            return 1.0;
+        }
+        let Some(last) = self.last_activity else {
+            return 0.0;
+        };
+        let Some(fading) = now.saturating_sub(last).checked_sub(self.hide_delay) else {
+            return 1.0;
+        };
+        if fading >= self.fade_duration {
+            return 0.0;
+        }
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "Millisecond durations are far below f64's integer precision."
+        )]
+        let t = fading as f64 / self.fade_duration as f64;
+        1.0 - t
+    }
+
+    /// Returns `true` if the scrollbar should be drawn at `now`.
+    #[must_use]
+    pub fn is_visible(&self, now: u64) -> bool {
+        self.opacity(now) > 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kurbo::{Rect, Vec2};
+
+    #[test]
+    fn thumb_is_proportional_to_visible_fraction() {
+        let bar = Scrollbar::new(0.0..100.0);
+        let layout = bar.layout(0.0..1000.0, 0.0..250.0).unwrap();
+        assert_eq!(layout.thumb, 0.0..25.0);
+        assert_eq!(layout.position, 0.0);
+
+        let layout = bar.layout(0.0..1000.0, 750.0..1000.0).unwrap();
+        assert_eq!(layout.thumb, 75.0..100.0);
+        assert_eq!(layout.position, 1.0);
+
+        let layout = bar.layout(0.0..1000.0, 375.0..625.0).unwrap();
+        assert_eq!(layout.thumb, 37.5..62.5);
+        assert_eq!(layout.position, 0.5);
+    }
+
+    #[test]
+    fn thumb_respects_minimum_length() {
+        let mut bar = Scrollbar::new(10.0..110.0);
+        bar.set_min_thumb_len(20.0);
+        let layout = bar.layout(0.0..10_000.0, 0.0..100.0).unwrap();
+        assert_eq!(layout.thumb, 10.0..30.0);
+        let layout = bar.layout(0.0..10_000.0, 9_900.0..10_000.0).unwrap();
+        assert_eq!(layout.thumb, 90.0..110.0);
+
+        // The thumb never exceeds the track.
+        bar.set_min_thumb_len(500.0);
+        let layout = bar.layout(0.0..10_000.0, 0.0..100.0).unwrap();
+        assert_eq!(layout.thumb, 10.0..110.0);
+    }
+
+    #[test]
+    fn nothing_to_scroll_or_invalid_returns_none() {
+        let bar = Scrollbar::new(0.0..100.0);
+        assert!(bar.layout(0.0..100.0, 0.0..100.0).is_none());
+        assert!(bar.layout(0.0..100.0, -10.0..200.0).is_none());
+        assert!(bar.layout(0.0..0.0, 0.0..10.0).is_none());
+        assert!(bar.layout(0.0..f64::NAN, 0.0..10.0).is_none());
+    }
+
+    #[test]
+    fn overscrolled_visible_range_extends_extent() {
+        let bar = Scrollbar::new(0.0..100.0);
+        let layout = bar.layout(0.0..100.0, 50.0..150.0).unwrap();
+        assert_eq!(layout.extent, 0.0..150.0);
+        assert_eq!(layout.position, 1.0);
+        let thumb_len = layout.thumb.end - layout.thumb.start;
+        assert!((thumb_len - 100.0 * 100.0 / 150.0).abs() < 1e-9);
+        assert_eq!(layout.thumb.end, 100.0);
+    }
+
+    #[test]
+    fn setters_ignore_invalid_values() {
+        let mut bar = Scrollbar::new(f64::NAN..1.0);
+        assert_eq!(bar.track(), 0.0..0.0);
+        bar.set_track(0.0..50.0);
+        bar.set_track(10.0..5.0);
+        assert_eq!(bar.track(), 0.0..50.0);
+        bar.set_min_thumb_len(-1.0);
+        bar.set_min_thumb_len(f64::INFINITY);
+        assert_eq!(bar.min_thumb_len(), 16.0);
+    }
+
+    #[test]
+    fn layout_from_viewports() {
+        let mut view = Viewport1D::new(0.0..100.0);
+        let bar = Scrollbar::new(0.0..100.0);
+        assert!(bar.layout_1d(&view).is_none());
+        view.set_world_bounds(Some(0.0..400.0));
+        view.pan_by_view(-100.0);
+        let layout = bar.layout_1d(&view).unwrap();
+        assert_eq!(layout.thumb, 25.0..50.0);
+
+        let mut view = Viewport2D::new(Rect::new(0.0, 0.0, 100.0, 50.0));
+        view.set_world_bounds(Some(Rect::new(0.0, 0.0, 100.0, 200.0)));
+        view.pan_by_view(Vec2::new(0.0, -150.0));
+        assert!(bar.layout_2d(&view, ScrollAxis::Horizontal).is_none());
+        let layout = bar.layout_2d(&view, ScrollAxis::Vertical).unwrap();
+        assert_eq!(layout.thumb, 75.0..100.0);
+        assert_eq!(layout.position, 1.0);
+    }
+
+    #[test]
+    fn part_at_and_page_delta() {
+        let bar = Scrollbar::new(0.0..100.0);
+        let layout = bar.layout(0.0..1000.0, 500.0..750.0).unwrap();
+        assert_eq!(layout.thumb, 50.0..75.0);
+        assert_eq!(layout.part_at(-1.0), None);
+        assert_eq!(layout.part_at(10.0), Some(ScrollbarPart::TrackBefore));
+        assert_eq!(layout.part_at(50.0), Some(ScrollbarPart::Thumb));
+        assert_eq!(layout.part_at(90.0), Some(ScrollbarPart::TrackAfter));
+        assert_eq!(layout.part_at(101.0), None);
+        assert_eq!(layout.page_delta(ScrollbarPart::TrackBefore), -250.0);
+        assert_eq!(layout.page_delta(ScrollbarPart::Thumb), 0.0);
+        assert_eq!(layout.page_delta(ScrollbarPart::TrackAfter), 250.0);
+    }
+
+    #[test]
+    fn thumb_drag_is_proportional() {
+        let bar = Scrollbar::new(0.0..100.0);
+        let layout = bar.layout(0.0..1000.0, 0.0..250.0).unwrap();
+        // 750 world units of scroll over 75 pixels of travel.
+        assert_eq!(layout.world_per_thumb_unit(), 10.0);
+        assert!(ScrollbarDrag::begin(&layout, 50.0).is_none());
+        let drag = ScrollbarDrag::begin(&layout, 10.0).unwrap();
+        assert_eq!(drag.world_start(10.0), 0.0);
+        assert_eq!(drag.world_start(85.0), 750.0);
+        assert_eq!(drag.world_start(f64::NAN), 0.0);
+    }
+
+    #[test]
+    fn rects_follow_axis() {
+        let bar = Scrollbar::new(0.0..100.0);
+        let layout = bar.layout(0.0..1000.0, 0.0..250.0).unwrap();
+        assert_eq!(
+            layout.thumb_rect(ScrollAxis::Vertical, 90.0..98.0),
+            Rect::new(90.0, 0.0, 98.0, 25.0)
+        );
+        assert_eq!(
+            layout.track_rect(ScrollAxis::Horizontal, 40.0..48.0),
+            Rect::new(0.0, 40.0, 100.0, 48.0)
+        );
+    }
+
+    #[test]
+    fn auto_hide_fades_after_inactivity() {
+        let mut auto_hide = ScrollbarAutoHide::new();
+        assert!(!auto_hide.is_visible(0));
+        auto_hide.on_activity(100);
+        assert_eq!(auto_hide.opacity(1100), 1.0);
+        assert_eq!(auto_hide.opacity(1200), 0.5);
+        assert_eq!(auto_hide.opacity(1300), 0.0);
+
+        // Held scrollbars stay visible; releasing restarts the delay.
+        auto_hide.set_held(true, 1300);
+        assert_eq!(auto_hide.opacity(5000), 1.0);
+        auto_hide.set_held(false, 5000);
+        assert_eq!(auto_hide.opacity(6000), 1.0);
+        assert!(!auto_hide.is_visible(6200));
+    }
+}
@@ -6,6 +6,7 @@ use core::ops::Range;
 use kurbo::Point;
 
 use crate::modes::{ClampMode, FitMode};
+use crate::overscroll::{RubberBand, resting_pan};
 use crate::validation::{
     nice_grid_spacing, normalize_zoom_limits, sanitize_zoom_value, view_span_is_valid,
     world_range_is_valid,
@@ -194,6 +195,76 @@ impl Viewport1D {
         self.clamp_to_bounds();
     }
 
+    /// Pans the view by a delta in view/device space, resisting movement past the world bounds.
+    ///
+    /// Within the bounds this behaves like [`Viewport1D::pan_by_view`]. Past them, the content
+    /// follows with the diminishing displacement of `rubber`, and [`Viewport1D::overscroll`]
+    /// reports how far it is displaced. Without world bounds this is a plain pan.
+    /// Non-finite deltas are ignored.
+    pub fn pan_by_view_elastic(&mut self, delta: f64, rubber: &RubberBand) {
+        let Some(bounds) = self.overscroll_bounds() else {
+            self.pan_by_view(delta);
+            return;
+        };
+        if delta == 0.0 || !delta.is_finite() {
+            return;
+        }
+        let len = self.view_span.end - self.view_span.start;
+        let (zoom, fit_mode) = (self.zoom, self.fit_mode);
+        let pan = rubber.pan_axis(self.pan, delta, |pan| {
+            resting_pan(pan, len, bounds.start, bounds.end, zoom, fit_mode)
+        });
+        if !pan.is_finite() {
+            return;
+        }
+        self.pan = pan;
+        self.clamp_to_bounds();
+    }
+
+    /// Returns how far the content is displaced past its resting position, in view units.
+    ///
+    /// The resting position keeps the view inside the world bounds, or places the bounds
+    /// according to the [`FitMode`] when they are shorter than the view. Positive values mean
+    /// the content is displaced towards the end of the span. Returns `0.0` without world bounds.
+    #[must_use]
+    pub fn overscroll(&self) -> f64 {
+        self.resting_pan().map_or(0.0, |rest| self.pan - rest)
+    }
+
+    /// Eases any overscroll back towards the resting position for one tick of `dt_ms`.
+    ///
+    /// Call this on every animation tick after the pan gesture ends. Returns `true` while the
+    /// content is still displaced and further ticks are needed.
+    pub fn settle_overscroll(&mut self, rubber: &RubberBand, dt_ms: u64) -> bool {
+        let Some(rest) = self.resting_pan() else {
+            return false;
+        };
+        let overscroll = self.pan - rest;
+        if overscroll == 0.0 {
+            return false;
+        }
+        let next = rubber.settle(overscroll, dt_ms);
+        self.pan = rest + next;
+        next != 0.0
+    }
+
+    fn resting_pan(&self) -> Option<f64> {
+        let bounds = self.overscroll_bounds()?;
+        let len = self.view_span.end - self.view_span.start;
+        Some(resting_pan(
+            self.pan,
+            len,
+            bounds.start,
+            bounds.end,
+            self.zoom,
+            self.fit_mode,
+        ))
+    }
+
+    fn overscroll_bounds(&self) -> Option<Range<f64>> {
+        self.world_bounds.clone().filter(|b| b.end > b.start)
+    }
+
     /// Zooms around a given anchor point in view/device coordinates.
     ///
     /// The anchor point remains fixed in view space as much as possible under
@@ -440,7 +511,7 @@ mod tests {
 
     use kurbo::Point;
 
-    use super::{ClampMode, FitMode, Viewport1D};
+    use super::{ClampMode, FitMode, RubberBand, Viewport1D};
 
     #[test]
     fn world_view_roundtrip_1d() {
@@ -490,6 +561,42 @@ mod tests {
         assert!((mapped_min - view_span.start).abs() < 1e-6);
     }
 
+    #[test]
+    fn elastic_pan_resists_and_settles_1d() {
+        let mut vp = Viewport1D::new(0.0..100.0);
+        vp.set_world_bounds(Some(0.0..1000.0));
+        let rubber = RubberBand::default();
+
+        // Inside the bounds elastic panning is a plain pan.
+        vp.pan_by_view_elastic(-50.0, &rubber);
+        assert_eq!(vp.overscroll(), 0.0);
+        assert_eq!(vp.visible_world_range(), 50.0..150.0);
+
+        // Past the start, only part of the drag is applied, and more drag adds less.
+        vp.pan_by_view_elastic(50.0, &rubber);
+        assert_eq!(vp.overscroll(), 0.0);
+        vp.pan_by_view_elastic(100.0, &rubber);
+        let first = vp.overscroll();
+        assert!(first > 0.0 && first < 50.0);
+        vp.pan_by_view_elastic(100.0, &rubber);
+        let second = vp.overscroll();
+        assert!(second - first < first);
+
+        // Dragging back applies the reverse curve before panning normally again.
+        vp.pan_by_view_elastic(-250.0, &rubber);
+        assert_eq!(vp.overscroll(), 0.0);
+
+        vp.pan_by_view_elastic(10_000.0, &rubber);
+        assert!(vp.overscroll() < rubber.limit);
+        let mut ticks = 0;
+        while vp.settle_overscroll(&rubber, 16) {
+            ticks += 1;
+        }
+        assert!(ticks > 1);
+        assert_eq!(vp.overscroll(), 0.0);
+        assert_eq!(vp.visible_world_range().start, 0.0);
+    }
+
     #[test]
     fn clamp_keeps_some_world_visible_1d() {
         let mut vp = Viewport1D::new(0.0..100.0);
@@ -4,6 +4,7 @@
 use kurbo::{Affine, Point, Rect, Vec2};
 
 use crate::modes::{ClampMode, FitMode};
+use crate::overscroll::{RubberBand, resting_pan};
 use crate::validation::{
     nice_grid_spacing, normalize_zoom_limits, point_is_finite, sanitize_zoom_value, vec2_is_finite,
     view_rect_is_valid, world_rect_is_valid,
@@ -27,6 +28,7 @@ pub struct Viewport2D {
     max_zoom: f64,
     clamp_mode: ClampMode,
     fit_mode: FitMode,
+    scale_factor: f64,
     world_to_view: Affine,
     view_to_world: Affine,
 }
@@ -37,6 +39,7 @@ impl Viewport2D {
     /// - Initial zoom is `1.0`.
     /// - Initial pan is zero (world origin maps to the view rect origin).
     /// - Zoom is clamped to the range `[1e-3, 1e3]` by default.
+    /// - The scale factor is `1.0`, so logical and device coordinates coincide.
     /// - Non-finite or negative-size view rects are treated as an empty rect at
     ///   the origin.
     #[must_use]
@@ -55,6 +58,7 @@ impl Viewport2D {
             max_zoom: 1e3,
             clamp_mode: ClampMode::default(),
             fit_mode: FitMode::default(),
+            scale_factor: 1.0,
             world_to_view: Affine::IDENTITY,
             view_to_world: Affine::IDENTITY,
         };
@@ -203,6 +207,159 @@ impl Viewport2D {
         self.clamp_to_bounds();
     }
 
+    /// Returns the ratio of device pixels to logical pixels, such as `2.0` on a high-DPI display.
+    #[must_use]
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Changes the ratio of device pixels to logical pixels, for example when the window moves
+    /// to a monitor with a different DPI.
+    ///
+    /// The view rect, pan, zoom, and zoom limits are rescaled together, so the same world region
+    /// stays visible at the same logical size and [`Viewport2D::logical_zoom`] is unchanged.
+    /// Non-finite, zero, negative, or subnormal factors are ignored.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        let Some(scale_factor) = sanitize_zoom_value(scale_factor) else {
+            return;
+        };
+        let ratio = scale_factor / self.scale_factor;
+        let view_rect = self.view_rect.scale_from_origin(ratio);
+        if ratio == 1.0 || !view_rect_is_valid(view_rect) {
+            return;
+        }
+        self.scale_factor = scale_factor;
+        self.view_rect = view_rect;
+        self.pan *= ratio;
+        self.zoom *= ratio;
+        self.min_zoom *= ratio;
+        self.max_zoom *= ratio;
+        self.rebuild_transforms();
+        self.clamp_to_bounds();
+    }
+
+    /// Returns the zoom relative to logical pixels, `zoom / scale_factor`.
+    ///
+    /// This is the zoom users see, for example `1.0` when one world unit covers one logical
+    /// pixel on any display.
+    #[must_use]
+    pub fn logical_zoom(&self) -> f64 {
+        self.zoom / self.scale_factor
+    }
+
+    /// Converts a point in logical pixels, such as a pointer position, into view/device
+    /// coordinates.
+    #[must_use]
+    pub fn logical_to_view_point(&self, pt: Point) -> Point {
+        (pt.to_vec2() * self.scale_factor).to_point()
+    }
+
+    /// Converts a view/device-space point into logical pixels.
+    #[must_use]
+    pub fn view_to_logical_point(&self, pt: Point) -> Point {
+        (pt.to_vec2() / self.scale_factor).to_point()
+    }
+
+    /// Pans the view by a delta in logical pixels, such as a pointer drag or scroll delta.
+    ///
+    /// This is [`Viewport2D::pan_by_view`] with the delta converted to device pixels.
+    pub fn pan_by_logical(&mut self, delta: Vec2) {
+        self.pan_by_view(delta * self.scale_factor);
+    }
+
+    /// Pans the view by a delta in view/device space, resisting movement past the world bounds.
+    ///
+    /// Within the bounds this behaves like [`Viewport2D::pan_by_view`]. Past them, the content
+    /// follows with the diminishing displacement of `rubber`, and [`Viewport2D::overscroll`]
+    /// reports how far it is displaced. Without world bounds this is a plain pan.
+    /// Non-finite deltas are ignored.
+    pub fn pan_by_view_elastic(&mut self, delta: Vec2, rubber: &RubberBand) {
+        let Some(bounds) = self.overscroll_bounds() else {
+            self.pan_by_view(delta);
+            return;
+        };
+        if delta == Vec2::ZERO || !vec2_is_finite(delta) {
+            return;
+        }
+        let size = self.view_rect.size();
+        let (zoom, fit_mode) = (self.zoom, self.fit_mode);
+        let pan = Vec2::new(
+            rubber.pan_axis(self.pan.x, delta.x, |pan| {
+                resting_pan(pan, size.width, bounds.x0, bounds.x1, zoom, fit_mode)
+            }),
+            rubber.pan_axis(self.pan.y, delta.y, |pan| {
+                resting_pan(pan, size.height, bounds.y0, bounds.y1, zoom, fit_mode)
+            }),
+        );
+        if !vec2_is_finite(pan) {
+            return;
+        }
+        self.pan = pan;
+        self.rebuild_transforms();
+        self.clamp_to_bounds();
+    }
+
+    /// Returns how far the content is displaced past its resting position, in view units.
+    ///
+    /// The resting position keeps the view inside the world bounds, or places the bounds
+    /// according to the [`FitMode`] along axes where they are smaller than the view. Positive
+    /// values mean the content is displaced right or down. Returns [`Vec2::ZERO`] without world
+    /// bounds.
+    #[must_use]
+    pub fn overscroll(&self) -> Vec2 {
+        self.resting_pan()
+            .map_or(Vec2::ZERO, |rest| self.pan - rest)
+    }
+
+    /// Eases any overscroll back towards the resting position for one tick of `dt_ms`.
+    ///
+    /// Call this on every animation tick after the pan gesture ends. Returns `true` while the
+    /// content is still displaced and further ticks are needed.
+    pub fn settle_overscroll(&mut self, rubber: &RubberBand, dt_ms: u64) -> bool {
+        let Some(rest) = self.resting_pan() else {
+            return false;
+        };
+        let overscroll = self.pan - rest;
+        if overscroll == Vec2::ZERO {
+            return false;
+        }
+        let next = Vec2::new(
+            rubber.settle(overscroll.x, dt_ms),
+            rubber.settle(overscroll.y, dt_ms),
+        );
+        self.pan = rest + next;
+        self.rebuild_transforms();
+        next != Vec2::ZERO
+    }
+
+    fn resting_pan(&self) -> Option<Vec2> {
+        let bounds = self.overscroll_bounds()?;
+        let size = self.view_rect.size();
+        Some(Vec2::new(
+            resting_pan(
+                self.pan.x,
+                size.width,
+                bounds.x0,
+                bounds.x1,
+                self.zoom,
+                self.fit_mode,
+            ),
+            resting_pan(
+                self.pan.y,
+                size.height,
+                bounds.y0,
+                bounds.y1,
+                self.zoom,
+                self.fit_mode,
+            ),
+        ))
+    }
+
+    fn overscroll_bounds(&self) -> Option<Rect> {
+        self.world_bounds
+            .filter(|b| b.width() > 0.0 && b.height() > 0.0)
+    }
+
     /// Zooms around a given anchor point in view/device coordinates.
     ///
     /// The anchor point remains fixed in view space as much as possible under
@@ -410,6 +567,7 @@ impl Viewport2D {
             max_zoom: self.max_zoom,
             clamp_mode: self.clamp_mode,
             fit_mode: self.fit_mode,
+            scale_factor: self.scale_factor,
         }
     }
 
@@ -490,6 +648,8 @@ pub struct Viewport2DDebugInfo {
     pub clamp_mode: ClampMode,
     /// Fit mode used by [`Viewport2D::fit_world`] / [`Viewport2D::fit_rect`].
     pub fit_mode: FitMode,
+    /// Ratio of device pixels to logical pixels.
+    pub scale_factor: f64,
 }
 
 #[cfg(test)]
@@ -526,6 +686,37 @@ mod tests {
         assert!((world_at_anchor_after.y - world_at_anchor_before.y).abs() < 1e-9);
     }
 
+    #[test]
+    fn scale_factor_change_keeps_logical_view() {
+        let mut vp = Viewport2D::new(Rect::new(0.0, 0.0, 400.0, 300.0));
+        vp.set_zoom(2.0);
+        vp.pan_by_logical(kurbo::Vec2::new(30.0, -10.0));
+        let visible = vp.visible_world_rect();
+        let pointer = Point::new(100.0, 50.0);
+        let world_at_pointer = vp.view_to_world_point(vp.logical_to_view_point(pointer));
+
+        vp.set_scale_factor(2.0);
+        assert_eq!(vp.view_rect(), Rect::new(0.0, 0.0, 800.0, 600.0));
+        assert_eq!(vp.zoom(), 4.0);
+        assert_eq!(vp.logical_zoom(), 2.0);
+        assert_eq!(vp.zoom_limits(), (2e-3, 2e3));
+        assert_eq!(vp.visible_world_rect(), visible);
+        assert_eq!(
+            vp.view_to_world_point(vp.logical_to_view_point(pointer)),
+            world_at_pointer
+        );
+
+        // Logical deltas now cover twice as many device pixels.
+        vp.pan_by_logical(kurbo::Vec2::new(10.0, 0.0));
+        assert_eq!(
+            vp.visible_world_rect(),
+            visible + kurbo::Vec2::new(-5.0, 0.0)
+        );
+
+        vp.set_scale_factor(0.0);
+        assert_eq!(vp.scale_factor(), 2.0);
+    }
+
     #[test]
     fn fit_world_respects_bounds_and_aspect_ratio() {
         let view_rect = Rect::new(0.0, 0.0, 200.0, 100.0);
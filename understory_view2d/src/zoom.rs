@@ -0,0 +1,278 @@
+// Copyright 2025 the Understory Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::validation::sanitize_zoom_value;
+
+/// Relative tolerance used when comparing a zoom against a ladder step.
+const STEP_EPSILON: f64 = 1e-9;
+
+/// A ladder of discrete zoom levels for stepped zooming.
+///
+/// Discrete inputs (keyboard shortcuts, zoom buttons) usually move between fixed levels such
+/// as 50%, 100%, and 200%, while continuous inputs (pinch, scroll) stay free-form. A
+/// `ZoomLadder` answers "what is the next level in or out from here", starting from whatever
+/// zoom the continuous inputs left behind.
+///
+/// Steps are expected in ascending order. Non-finite and non-positive steps are skipped.
+///
+/// Steps are logical zoom levels, so 1.0 means one world unit per logical pixel. With a
+/// [`Viewport2D`](crate::Viewport2D), step from [`Viewport2D::logical_zoom`] and multiply the
+/// result by [`Viewport2D::scale_factor`] to get back to the device zoom the viewport uses.
+///
+/// [`Viewport2D::logical_zoom`]: crate::Viewport2D::logical_zoom
+/// [`Viewport2D::scale_factor`]: crate::Viewport2D::scale_factor
+///
+/// ```rust
+/// use kurbo::{Point, Rect};
+/// use understory_view2d::{Viewport2D, ZoomLadder};
+///
+/// let mut view = Viewport2D::new(Rect::new(0.0, 0.0, 800.0, 600.0));
+/// view.set_scale_factor(2.0);
+/// let ladder = ZoomLadder::default();
+///
+/// // After a pinch left the logical zoom at 1.3, "zoom in" goes to the next step.
+/// view.set_zoom(1.3 * view.scale_factor());
+/// let target = ladder.step_in(view.logical_zoom()) * view.scale_factor();
+/// view.zoom_about_view_point(Point::new(800.0, 600.0), target / view.zoom());
+/// assert!((view.logical_zoom() - 1.5).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ZoomLadder<'a> {
+    steps: &'a [f64],
+}
+
+impl ZoomLadder<'static> {
+    /// The default zoom levels, from 10% to 3200%.
+    pub const DEFAULT_STEPS: &'static [f64] = &[
+        0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0, 32.0,
+    ];
+}
+
+impl Default for ZoomLadder<'static> {
+    fn default() -> Self {
+        Self::new(ZoomLadder::DEFAULT_STEPS)
+    }
+}
+
+impl<'a> ZoomLadder<'a> {
+    /// Creates a ladder over `steps`, given in ascending order.
+    #[must_use]
+    pub const fn new(steps: &'a [f64]) -> Self {
+        Self { steps }
+    }
+
+    /// Returns the ladder steps.
+    #[must_use]
+    pub fn steps(&self) -> &'a [f64] {
+        self.steps
+    }
+
+    fn valid_steps(&self) -> impl DoubleEndedIterator<Item = f64> + 'a {
+        self.steps
+            .iter()
+            .copied()
+            .filter(|step| sanitize_zoom_value(*step).is_some())
+    }
+
+    /// Returns the smallest step strictly greater than `zoom`.
+    ///
+    /// Returns `zoom` unchanged if it is at or above the top of the ladder, or is invalid.
+    #[must_use]
+    pub fn step_in(&self, zoom: f64) -> f64 {
+        if sanitize_zoom_value(zoom).is_none() {
+            return zoom;
+        }
+        self.valid_steps()
+            .find(|step| *step > zoom * (1.0 + STEP_EPSILON))
+            .unwrap_or(zoom)
+    }
+
+    /// Returns the largest step strictly less than `zoom`.
+    ///
+    /// Returns `zoom` unchanged if it is at or below the bottom of the ladder, or is invalid.
+    #[must_use]
+    pub fn step_out(&self, zoom: f64) -> f64 {
+        if sanitize_zoom_value(zoom).is_none() {
+            return zoom;
+        }
+        self.valid_steps()
+            .rev()
+            .find(|step| *step < zoom * (1.0 - STEP_EPSILON))
+            .unwrap_or(zoom)
+    }
+
+    /// Returns the step closest to `zoom` by ratio, so 1.4 is closer to 2.0 than to 0.75.
+    ///
+    /// Returns `zoom` unchanged if the ladder is empty or `zoom` is invalid.
+    #[must_use]
+    pub fn nearest(&self, zoom: f64) -> f64 {
+        if sanitize_zoom_value(zoom).is_none() {
+            return zoom;
+        }
+        let ratio = |step: f64| {
+            if step > zoom {
+                step / zoom
+            } else {
+                zoom / step
+            }
+        };
+        self.valid_steps()
+            .min_by(|a, b| ratio(*a).total_cmp(&ratio(*b)))
+            .unwrap_or(zoom)
+    }
+}
+
+/// Snaps zoom levels that land close to a preferred value, typically 100%.
+///
+/// Continuous zooming rarely lands exactly on 1.0, which leaves content slightly blurry.
+/// Passing the result of each continuous zoom through [`ZoomSnap::apply`] makes exact
+/// scale easy to hit without making continuous zoom feel stepped.
+///
+/// The target is a logical zoom level. With a [`Viewport2D`](crate::Viewport2D), apply the snap
+/// to [`Viewport2D::logical_zoom`] and set the device zoom to the result times
+/// [`Viewport2D::scale_factor`]; snapping the device zoom would aim for 100% device pixels,
+/// which is 50% logical on a 2× display.
+///
+/// [`Viewport2D::logical_zoom`]: crate::Viewport2D::logical_zoom
+/// [`Viewport2D::scale_factor`]: crate::Viewport2D::scale_factor
+///
+/// ```rust
+/// use kurbo::Rect;
+/// use understory_view2d::{Viewport2D, ZoomSnap};
+///
+/// let mut view = Viewport2D::new(Rect::new(0.0, 0.0, 800.0, 600.0));
+/// view.set_scale_factor(2.0);
+/// view.set_zoom(1.03 * view.scale_factor());
+///
+/// let snapped = ZoomSnap::default().apply(view.logical_zoom());
+/// view.set_zoom(snapped * view.scale_factor());
+/// assert_eq!(view.logical_zoom(), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomSnap {
+    /// The zoom level to snap to.
+    pub target: f64,
+    /// Relative distance from `target` within which zoom snaps, for example `0.05` for ±5%.
+    pub tolerance: f64,
+}
+
+impl Default for ZoomSnap {
+    /// Snap to 100% within ±4%.
+    fn default() -> Self {
+        Self {
+            target: 1.0,
+            tolerance: 0.04,
+        }
+    }
+}
+
+impl ZoomSnap {
+    /// Returns `target` if `zoom` is within the relative tolerance of it, or `zoom` otherwise.
+    ///
+    /// Invalid zoom values, targets, or tolerances leave `zoom` unchanged.
+    #[must_use]
+    pub fn apply(&self, zoom: f64) -> f64 {
+        let (Some(zoom_ok), Some(target)) =
+            (sanitize_zoom_value(zoom), sanitize_zoom_value(self.target))
+        else {
+            return zoom;
+        };
+        if !(self.tolerance.is_finite() && self.tolerance >= 0.0) {
+            return zoom;
+        }
+        if (zoom_ok / target - 1.0).abs() <= self.tolerance {
+            target
+        } else {
+            zoom
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_in_and_out_follow_ladder() {
+        let ladder = ZoomLadder::new(&[0.5, 1.0, 2.0]);
+        assert_eq!(ladder.step_in(0.5), 1.0);
+        assert_eq!(ladder.step_in(0.7), 1.0);
+        assert_eq!(ladder.step_in(1.0), 2.0);
+        assert_eq!(ladder.step_in(2.0), 2.0);
+        assert_eq!(ladder.step_in(0.1), 0.5);
+        assert_eq!(ladder.step_out(2.0), 1.0);
+        assert_eq!(ladder.step_out(1.5), 1.0);
+        assert_eq!(ladder.step_out(0.5), 0.5);
+        assert_eq!(ladder.step_out(5.0), 2.0);
+    }
+
+    #[test]
+    fn near_equal_zoom_counts_as_on_step() {
+        let ladder = ZoomLadder::new(&[0.5, 1.0, 2.0]);
+        assert_eq!(ladder.step_in(1.0 - 1e-12), 2.0);
+        assert_eq!(ladder.step_out(1.0 + 1e-12), 0.5);
+    }
+
+    #[test]
+    fn nearest_uses_ratio() {
+        let ladder = ZoomLadder::new(&[0.75, 2.0]);
+        assert_eq!(ladder.nearest(1.4), 2.0);
+        assert_eq!(ladder.nearest(1.1), 0.75);
+        assert_eq!(ZoomLadder::new(&[]).nearest(1.1), 1.1);
+    }
+
+    #[test]
+    fn invalid_steps_and_zoom_are_ignored() {
+        let ladder = ZoomLadder::new(&[f64::NAN, -1.0, 0.0, 1.0, f64::INFINITY]);
+        assert_eq!(ladder.step_in(0.5), 1.0);
+        assert_eq!(ladder.step_in(1.0), 1.0);
+        assert_eq!(ladder.step_out(2.0), 1.0);
+        assert!(ladder.step_in(f64::NAN).is_nan());
+        assert_eq!(ladder.step_out(-3.0), -3.0);
+    }
+
+    #[test]
+    fn default_ladder_contains_one() {
+        assert!(ZoomLadder::default().steps().contains(&1.0));
+    }
+
+    #[test]
+    fn snap_window_around_target() {
+        let snap = ZoomSnap::default();
+        assert_eq!(snap.apply(1.03), 1.0);
+        assert_eq!(snap.apply(0.97), 1.0);
+        assert_eq!(snap.apply(1.05), 1.05);
+        assert!(snap.apply(f64::NAN).is_nan());
+
+        let snap = ZoomSnap {
+            target: 2.0,
+            tolerance: 0.1,
+        };
+        assert_eq!(snap.apply(2.15), 2.0);
+        assert_eq!(snap.apply(2.25), 2.25);
+        let broken = ZoomSnap {
+            target: 1.0,
+            tolerance: f64::NAN,
+        };
+        assert_eq!(broken.apply(1.01), 1.01);
+    }
+
+    // On a 2x display, snapping the logical zoom lands on 100% logical, while the raw device
+    // zoom would not snap at all.
+    #[test]
+    fn snap_logical_zoom_with_scale_factor() {
+        let mut view = crate::Viewport2D::new(kurbo::Rect::new(0.0, 0.0, 400.0, 300.0));
+        view.set_scale_factor(2.0);
+        view.set_zoom(0.98 * view.scale_factor());
+        let snap = ZoomSnap::default();
+        assert_eq!(snap.apply(view.zoom()), view.zoom());
+
+        view.set_zoom(snap.apply(view.logical_zoom()) * view.scale_factor());
+        assert_eq!(view.zoom(), 2.0);
+        assert_eq!(view.logical_zoom(), 1.0);
+        assert_eq!(
+            ZoomLadder::default().step_in(view.logical_zoom()) * view.scale_factor(),
+            3.0
+        );
+    }
+}